@@ -1,15 +1,53 @@
-import { LogOut, User, Bell, Settings } from 'lucide-react';
+import { useEffect, useState } from 'react';
+import { LogOut, User, Bell, Settings, RefreshCw, AlertTriangle } from 'lucide-react';
 import { supabase } from '../services/supabase';
+import { binanceService, maintenanceNoGoReason, StreamStatus } from '../services/binance';
 import { useStore } from '../store/useStore';
 import './Header.css';
 
+const SYSTEM_STATUS_POLL_MS = 60000;
+
 export default function Header() {
-  const { user, marketStats } = useStore();
+  const {
+    user,
+    marketStats,
+    exchangeNoGoReason,
+    setExchangeNoGoReason,
+    dataNoGoReason,
+    setDataNoGoReason,
+  } = useStore();
+  const [streamStatus, setStreamStatus] = useState<StreamStatus>(binanceService.getStreamStatus());
+
+  useEffect(() => binanceService.subscribeToStatus(setStreamStatus), []);
+
+  useEffect(() => {
+    const checkSystemStatus = async () => {
+      const status = await binanceService.getSystemStatus();
+      setExchangeNoGoReason(maintenanceNoGoReason(status, useStore.getState().exchangeNoGoReason));
+    };
+
+    checkSystemStatus();
+    const interval = setInterval(checkSystemStatus, SYSTEM_STATUS_POLL_MS);
+
+    return () => clearInterval(interval);
+  }, [setExchangeNoGoReason]);
 
   const handleLogout = async () => {
     await supabase.auth.signOut();
   };
 
+  const streamState = streamStatus.dead
+    ? 'dead'
+    : streamStatus.connected ? 'live' : 'reconnecting';
+
+  const streamLabel = streamStatus.dead
+    ? 'Stream down'
+    : streamStatus.connected
+      ? 'Live'
+      : streamStatus.reconnectAttempts > 0
+        ? `Reconnecting (${streamStatus.reconnectAttempts})`
+        : 'Connecting';
+
   return (
     <header className="header">
       <div className="header-left">
@@ -29,6 +67,34 @@ export default function Header() {
       </div>
 
       <div className="header-right">
+        {exchangeNoGoReason && (
+          <div className="maintenance-banner" title="Order entry is paused until Binance reports normal status">
+            <AlertTriangle size={14} />
+            <span>{exchangeNoGoReason}</span>
+          </div>
+        )}
+
+        {dataNoGoReason && (
+          <div className="maintenance-banner" title="Order entry is paused after repeated bad kline data">
+            <AlertTriangle size={14} />
+            <span>{dataNoGoReason}</span>
+            <button className="reconnect-btn" onClick={() => setDataNoGoReason(null)}>
+              Resume
+            </button>
+          </div>
+        )}
+
+        <div className={`stream-status ${streamState}`} title={streamStatus.noGoReason ?? undefined}>
+          <span className="status-dot" />
+          <span>{streamLabel}</span>
+          {streamStatus.dead && (
+            <button className="reconnect-btn" onClick={() => binanceService.resetStream()}>
+              <RefreshCw size={14} />
+              Reconnect
+            </button>
+          )}
+        </div>
+
         <button className="header-btn" title="Notifications">
           <Bell size={20} />
           <span className="notification-badge">3</span>
@@ -1,28 +1,37 @@
 import { useEffect, useState } from 'react';
 import { binanceService } from '../services/binance';
+import { isOrderBookStale } from '../services/orderbook';
 import { useStore } from '../store/useStore';
 import { OrderBook as OrderBookType } from '../types';
 import './OrderBook.css';
 
+const MAX_ORDERBOOK_AGE_SECS = parseFloat(import.meta.env.VITE_MAX_ORDERBOOK_AGE_SECS ?? '') || 10;
+
 export default function OrderBook() {
   const { selectedSymbol } = useStore();
   const [orderBook, setOrderBook] = useState<OrderBookType | null>(null);
   const [loading, setLoading] = useState(true);
+  const [updatedAt, setUpdatedAt] = useState<number | null>(null);
+  const [now, setNow] = useState(Date.now());
 
   useEffect(() => {
     setLoading(true);
+    setUpdatedAt(null);
 
     binanceService.getOrderBook(selectedSymbol, 20).then((data) => {
       if (data) {
         setOrderBook(data);
+        setUpdatedAt(Date.now());
         setLoading(false);
       }
     });
 
     const interval = setInterval(() => {
+      setNow(Date.now());
       binanceService.getOrderBook(selectedSymbol, 20).then((data) => {
         if (data) {
           setOrderBook(data);
+          setUpdatedAt(Date.now());
         }
       });
     }, 2000);
@@ -46,6 +55,7 @@ export default function OrderBook() {
     );
   }
 
+  const stale = isOrderBookStale(updatedAt, MAX_ORDERBOOK_AGE_SECS, now);
   const maxBidVolume = Math.max(...orderBook.bids.map(b => parseFloat(b[1])));
   const maxAskVolume = Math.max(...orderBook.asks.map(a => parseFloat(a[1])));
 
@@ -53,6 +63,11 @@ export default function OrderBook() {
     <div className="order-book">
       <div className="order-book-header">
         <h3>Order Book</h3>
+        {stale && (
+          <span className="stale-badge" title={`No update for over ${MAX_ORDERBOOK_AGE_SECS}s`}>
+            Stale
+          </span>
+        )}
         <div className="order-book-legend">
           <span className="legend-item">
             <span className="legend-color bid" />
@@ -71,7 +86,7 @@ export default function OrderBook() {
         <span>Total</span>
       </div>
 
-      <div className="order-book-content">
+      <div className={`order-book-content ${stale ? 'stale' : ''}`}>
         <div className="asks">
           {orderBook.asks.slice(0, 15).reverse().map((ask, index) => {
             const price = parseFloat(ask[0]);
@@ -91,7 +106,7 @@ export default function OrderBook() {
         </div>
 
         <div className="spread">
-          {orderBook.asks.length > 0 && orderBook.bids.length > 0 && (
+          {!stale && orderBook.asks.length > 0 && orderBook.bids.length > 0 && (
             <>
               <span className="spread-value">
                 {(parseFloat(orderBook.asks[0][0]) - parseFloat(orderBook.bids[0][0])).toFixed(2)}
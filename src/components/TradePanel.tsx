@@ -1,13 +1,39 @@
 import { useState, useEffect } from 'react';
 import { binanceService } from '../services/binance';
+import { checkRoundingLoss, roundPrice, roundQuantity, validateOrder } from '../services/filters';
+import { simulateMarketFill, bookPriceDecimals, makerEntryPrice, bestPrice } from '../services/orderbook';
 import { useStore } from '../store/useStore';
+import { SymbolFilters } from '../types';
 import './TradePanel.css';
 
 type OrderType = 'LIMIT' | 'MARKET' | 'STOP_LIMIT';
 type OrderSide = 'BUY' | 'SELL';
 
+const DEPTH_LEVELS = 100;
+
+// Price to use when the ticker has nothing for the symbol yet
+async function fetchFallbackPrice(symbol: string): Promise<number | null> {
+  const [orderBook, klines] = await Promise.all([
+    binanceService.getOrderBook(symbol, 5),
+    binanceService.getKlines(symbol, '1m', 1),
+  ]);
+  return bestPrice(0, orderBook, klines[klines.length - 1]?.close ?? null);
+}
+
 export default function TradePanel() {
-  const { selectedSymbol } = useStore();
+  const {
+    selectedSymbol,
+    simulateDepthFills,
+    setSimulateDepthFills,
+    makerAggressiveness,
+    setMakerAggressiveness,
+    exchangeNoGoReason,
+    dataNoGoReason,
+  } = useStore();
+  const [streamNoGoReason, setStreamNoGoReason] = useState<string | null>(
+    binanceService.getStreamStatus().noGoReason
+  );
+  const noGoReason = streamNoGoReason ?? exchangeNoGoReason ?? dataNoGoReason;
   const [orderType, setOrderType] = useState<OrderType>('LIMIT');
   const [orderSide, setOrderSide] = useState<OrderSide>('BUY');
   const [price, setPrice] = useState('');
@@ -15,8 +41,27 @@ export default function TradePanel() {
   const [amount, setAmount] = useState('');
   const [total, setTotal] = useState('');
   const [currentPrice, setCurrentPrice] = useState(0);
+  const [fallbackPrice, setFallbackPrice] = useState<number | null>(null);
+  const [filters, setFilters] = useState<SymbolFilters | null>(null);
+
+  useEffect(
+    () => binanceService.subscribeToStatus((status) => setStreamNoGoReason(status.noGoReason)),
+    []
+  );
+
+  useEffect(() => {
+    setFilters(null);
+    binanceService.getSymbolFilters(selectedSymbol).then(setFilters);
+  }, [selectedSymbol]);
+
+  // Derived amounts have to land on the step or the input rejects them
+  const formatAmount = (value: number) =>
+    filters ? String(roundQuantity(filters, value)) : value.toFixed(6);
 
   useEffect(() => {
+    setCurrentPrice(0);
+    setFallbackPrice(null);
+
     binanceService.getTicker(selectedSymbol).then((ticker) => {
       if (ticker) {
         const tickerPrice = parseFloat(ticker.price);
@@ -24,6 +69,8 @@ export default function TradePanel() {
         if (!price) {
           setPrice(tickerPrice.toFixed(2));
         }
+      } else {
+        fetchFallbackPrice(selectedSymbol).then(setFallbackPrice);
       }
     });
 
@@ -39,11 +86,16 @@ export default function TradePanel() {
     if (price && amount) {
       setTotal((parseFloat(price) * parseFloat(amount)).toFixed(2));
     } else if (total && price) {
-      setAmount((parseFloat(total) / parseFloat(price)).toFixed(6));
+      setAmount(formatAmount(parseFloat(total) / parseFloat(price)));
     }
   }, [price, amount]);
 
-  const handleSubmit = (e: React.FormEvent) => {
+  // The ticker can still be empty right after a symbol switch
+  const referencePrice = currentPrice > 0 ? currentPrice : fallbackPrice;
+  const resolveMarketPrice = async () =>
+    currentPrice > 0 ? currentPrice : fetchFallbackPrice(selectedSymbol);
+
+  const handleSubmit = async (e: React.FormEvent) => {
     e.preventDefault();
     console.log('Order submitted:', {
       symbol: selectedSymbol,
@@ -54,15 +106,83 @@ export default function TradePanel() {
       amount,
       total,
     });
+
+    if (noGoReason) {
+      alert(`${noGoReason}: trading is paused. Order not placed.`);
+      return;
+    }
+
+    const filters = await binanceService.getSymbolFilters(selectedSymbol);
+    if (!filters) {
+      alert(`Could not load the trading rules for ${selectedSymbol}. Order not placed.`);
+      return;
+    }
+
+    const marketPrice = orderType === 'MARKET' ? await resolveMarketPrice() : null;
+    if (orderType === 'MARKET' && marketPrice === null) {
+      alert(`No price is available for ${selectedSymbol} yet. Order not placed.`);
+      return;
+    }
+
+    // Binance rejects quantities and prices off the symbol's step and tick
+    const quantity = roundQuantity(filters, parseFloat(amount));
+    const orderPrice = marketPrice ?? roundPrice(filters, parseFloat(price));
+    const rejection =
+      checkRoundingLoss(parseFloat(amount), quantity) ?? validateOrder(filters, quantity, orderPrice);
+    if (rejection) {
+      alert(`${rejection}. Order not placed.`);
+      return;
+    }
+    setAmount(String(quantity));
+    if (orderType !== 'MARKET') {
+      setPrice(String(orderPrice));
+    }
+    if (orderType === 'STOP_LIMIT') {
+      setStopPrice(String(roundPrice(filters, parseFloat(stopPrice))));
+    }
+
+    if (orderType === 'MARKET' && simulateDepthFills) {
+      const orderBook = await binanceService.getOrderBook(selectedSymbol, DEPTH_LEVELS);
+      if (!orderBook) {
+        alert('Could not load the order book to simulate the fill. Order not placed.');
+        return;
+      }
+
+      const fill = simulateMarketFill(orderBook, orderSide, quantity);
+      const decimals = bookPriceDecimals(orderBook);
+      alert(
+        `Order ${fill.partial ? 'partially filled' : 'filled'} (Demo mode)\n` +
+        `Filled: ${fill.filledQuantity.toFixed(6)} / ${fill.requestedQuantity.toFixed(6)}\n` +
+        `Avg price: $${fill.averagePrice.toFixed(decimals)} across ${fill.levelsConsumed} levels` +
+        (fill.partial ? `\nOnly the top ${fill.levelsAvailable} book levels were fetched; deeper liquidity was not simulated.` : '')
+      );
+      return;
+    }
+
     alert('Order placed successfully! (Demo mode)');
   };
 
+  const applyMakerPrice = async () => {
+    const [orderBook, symbolFilters] = await Promise.all([
+      binanceService.getOrderBook(selectedSymbol, 5),
+      binanceService.getSymbolFilters(selectedSymbol),
+    ]);
+    const makerPrice =
+      orderBook && symbolFilters &&
+      makerEntryPrice(orderBook, orderSide, makerAggressiveness, symbolFilters);
+    if (!orderBook || !symbolFilters || makerPrice === null) {
+      alert('Could not load the order book or trading rules to price the order.');
+      return;
+    }
+    setPrice(String(makerPrice));
+  };
+
   const setPercentage = (percent: number) => {
     const mockBalance = 10000;
     const calculatedTotal = (mockBalance * percent) / 100;
     setTotal(calculatedTotal.toFixed(2));
     if (price) {
-      setAmount((calculatedTotal / parseFloat(price)).toFixed(6));
+      setAmount(formatAmount(calculatedTotal / parseFloat(price)));
     }
   };
 
@@ -98,7 +218,7 @@ export default function TradePanel() {
       <form onSubmit={handleSubmit} className="trade-form">
         <div className="current-price">
           <span className="label">Current Price</span>
-          <span className="value">${currentPrice.toFixed(2)}</span>
+          <span className="value">{referencePrice !== null ? `$${referencePrice.toFixed(2)}` : '--'}</span>
         </div>
 
         {orderType !== 'MARKET' && (
@@ -106,7 +226,7 @@ export default function TradePanel() {
             <label>Price (USDT)</label>
             <input
               type="number"
-              step="0.01"
+              step={filters?.tickSize || '0.01'}
               value={price}
               onChange={(e) => setPrice(e.target.value)}
               placeholder="0.00"
@@ -115,12 +235,31 @@ export default function TradePanel() {
           </div>
         )}
 
+        {orderType === 'LIMIT' && (
+          <div className="maker-price">
+            <label className="trade-option">
+              Maker aggressiveness {Math.round(makerAggressiveness * 100)}%
+              <input
+                type="range"
+                min="0"
+                max="1"
+                step="0.05"
+                value={makerAggressiveness}
+                onChange={(e) => setMakerAggressiveness(parseFloat(e.target.value))}
+              />
+            </label>
+            <button type="button" className="percent-btn" onClick={applyMakerPrice}>
+              Price from book
+            </button>
+          </div>
+        )}
+
         {orderType === 'STOP_LIMIT' && (
           <div className="form-group">
             <label>Stop Price (USDT)</label>
             <input
               type="number"
-              step="0.01"
+              step={filters?.tickSize || '0.01'}
               value={stopPrice}
               onChange={(e) => setStopPrice(e.target.value)}
               placeholder="0.00"
@@ -133,7 +272,7 @@ export default function TradePanel() {
           <label>Amount</label>
           <input
             type="number"
-            step="0.000001"
+            step={filters?.stepSize || '0.000001'}
             value={amount}
             onChange={(e) => setAmount(e.target.value)}
             placeholder="0.000000"
@@ -141,6 +280,17 @@ export default function TradePanel() {
           />
         </div>
 
+        {orderType === 'MARKET' && (
+          <label className="trade-option">
+            <input
+              type="checkbox"
+              checked={simulateDepthFills}
+              onChange={(e) => setSimulateDepthFills(e.target.checked)}
+            />
+            Simulate fill against order book depth
+          </label>
+        )}
+
         <div className="percentage-buttons">
           {[25, 50, 75, 100].map((percent) => (
             <button
@@ -165,7 +315,12 @@ export default function TradePanel() {
           />
         </div>
 
-        <button type="submit" className={`submit-btn ${orderSide.toLowerCase()}`}>
+        <button
+          type="submit"
+          className={`submit-btn ${orderSide.toLowerCase()}`}
+          disabled={noGoReason !== null}
+          title={noGoReason ?? undefined}
+        >
           {orderSide} {selectedSymbol.replace('USDT', '')}
         </button>
 
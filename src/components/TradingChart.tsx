@@ -1,8 +1,18 @@
 import { useEffect, useRef, useState } from 'react';
-import { createChart, IChartApi, ISeriesApi } from 'lightweight-charts';
+import { createChart, IChartApi, ISeriesApi, LineStyle, SeriesType } from 'lightweight-charts';
 import { binanceService } from '../services/binance';
+import { IntegrityMonitor } from '../services/integrity';
 import { useStore } from '../store/useStore';
 import { Kline } from '../types';
+import { capWick, sanitizeCandles } from '../indicators/candles';
+import {
+  ChartIndicator,
+  hasEnoughCandles,
+  IndicatorStream,
+  oscillators,
+  overlays,
+  seriesMinCandles,
+} from './chartIndicators';
 import './TradingChart.css';
 
 const intervals = [
@@ -14,11 +24,87 @@ const intervals = [
   { label: '1d', value: '1d' },
 ];
 
+const MAX_CANDLES = 1000;
+
+interface ActiveIndicator {
+  indicator: ChartIndicator;
+  series: ISeriesApi<SeriesType>[];
+  stream: IndicatorStream | null;
+}
+
+function addIndicatorSeries(
+  chart: IChartApi,
+  indicator: ChartIndicator,
+  priceScaleId: string
+): ISeriesApi<SeriesType>[] {
+  return indicator.series.map((definition) => {
+    const options = {
+      color: definition.color,
+      priceScaleId,
+      priceLineVisible: false,
+    };
+    return definition.type === 'histogram'
+      ? chart.addHistogramSeries(options)
+      : chart.addLineSeries({ ...options, lineWidth: 1 });
+  });
+}
+
+function candlesNeeded(indicator: ChartIndicator): string {
+  const most = Math.max(...indicator.series.map((_, i) => seriesMinCandles(indicator, i)));
+  return most > indicator.minCandles
+    ? `Needs ${indicator.minCandles}-${most} candles`
+    : `Needs ${indicator.minCandles} candles`;
+}
+
+function refreshMarkers(
+  series: ISeriesApi<'Candlestick'> | null,
+  indicators: ActiveIndicator[],
+  candles: Kline[]
+) {
+  if (!series) return;
+  const markers = indicators
+    .flatMap((active) =>
+      active.indicator.markers && hasEnoughCandles(active.indicator, candles.length)
+        ? active.indicator.markers(candles)
+        : []
+    )
+    .sort((a, b) => a.time - b.time);
+  series.setMarkers(markers as any);
+}
+
+// Recomputes an indicator over the full candle history and, if it supports
+// streaming, restarts its stream from there. Each series is left empty until
+// there are enough candles for it.
+function refreshIndicator(active: ActiveIndicator, candles: Kline[]) {
+  const ready = hasEnoughCandles(active.indicator, candles.length);
+  const data = ready ? active.indicator.compute(candles) : [];
+  active.series.forEach((series, i) => {
+    const points = hasEnoughCandles(active.indicator, candles.length, i) ? data[i] : null;
+    series.setData((points ?? []) as any);
+  });
+  active.stream = ready && active.indicator.stream ? active.indicator.stream(candles) : null;
+}
+
 export default function TradingChart() {
   const chartContainerRef = useRef<HTMLDivElement>(null);
   const chartRef = useRef<IChartApi | null>(null);
   const seriesRef = useRef<ISeriesApi<'Candlestick'> | null>(null);
-  const { selectedSymbol, chartInterval, setChartInterval } = useStore();
+  // Indicator input, wick-capped so an exchange glitch can't skew the
+  // indicators. The candle series itself still shows what Binance sent.
+  const klinesRef = useRef<Kline[]>([]);
+  const indicatorsRef = useRef<ActiveIndicator[]>([]);
+  const integrityRef = useRef(new IntegrityMonitor());
+  const {
+    selectedSymbol,
+    chartInterval,
+    setChartInterval,
+    chartOscillator,
+    setChartOscillator,
+    chartOverlays,
+    toggleChartOverlay,
+    dataNoGoReason,
+    setDataNoGoReason,
+  } = useStore();
   const [loading, setLoading] = useState(true);
 
   useEffect(() => {
@@ -69,6 +155,9 @@ export default function TradingChart() {
     return () => {
       window.removeEventListener('resize', handleResize);
       chart.remove();
+      chartRef.current = null;
+      seriesRef.current = null;
+      indicatorsRef.current = [];
     };
   }, []);
 
@@ -76,10 +165,17 @@ export default function TradingChart() {
     if (!seriesRef.current) return;
 
     setLoading(true);
+    klinesRef.current = [];
+    integrityRef.current.reset();
+    indicatorsRef.current.forEach((active) => refreshIndicator(active, []));
+    refreshMarkers(seriesRef.current, indicatorsRef.current, []);
 
     binanceService.getKlines(selectedSymbol, chartInterval, 500).then((klines) => {
       if (seriesRef.current && klines.length > 0) {
+        klinesRef.current = sanitizeCandles(klines);
         seriesRef.current.setData(klines as any);
+        indicatorsRef.current.forEach((active) => refreshIndicator(active, klinesRef.current));
+        refreshMarkers(seriesRef.current, indicatorsRef.current, klinesRef.current);
         setLoading(false);
       }
     });
@@ -88,16 +184,97 @@ export default function TradingChart() {
       selectedSymbol,
       chartInterval,
       (kline: Kline) => {
+        const noGoReason = integrityRef.current.record(kline);
+        if (noGoReason) {
+          setDataNoGoReason(noGoReason);
+        }
+
         if (seriesRef.current) {
           seriesRef.current.update(kline as any);
         }
+
+        const candle = capWick(kline);
+        const klines = klinesRef.current;
+        const last = klines[klines.length - 1];
+        if (last && last.time === candle.time) {
+          klines[klines.length - 1] = candle;
+        } else if (!last || candle.time > last.time) {
+          klines.push(candle);
+          if (klines.length > MAX_CANDLES) {
+            klines.shift();
+          }
+        }
+
+        indicatorsRef.current.forEach((active) => {
+          if (active.stream) {
+            active.stream.next(candle).forEach((point, i) => {
+              if (point && hasEnoughCandles(active.indicator, klines.length, i)) {
+                active.series[i].update(point as any);
+              }
+            });
+          } else {
+            refreshIndicator(active, klines);
+          }
+        });
+        refreshMarkers(seriesRef.current, indicatorsRef.current, klines);
       }
     );
 
     return () => {
       unsubscribe();
     };
-  }, [selectedSymbol, chartInterval]);
+  }, [selectedSymbol, chartInterval, setDataNoGoReason]);
+
+  // Resuming from the Header clears the reason; start counting afresh
+  useEffect(() => {
+    if (dataNoGoReason === null) {
+      integrityRef.current.reset();
+    }
+  }, [dataNoGoReason]);
+
+  useEffect(() => {
+    const chart = chartRef.current;
+    if (!chart) return;
+
+    indicatorsRef.current.forEach((active) =>
+      active.series.forEach((series) => chart.removeSeries(series))
+    );
+
+    const oscillator = oscillators.find((o) => o.id === chartOscillator);
+
+    chart.priceScale('right').applyOptions({
+      scaleMargins: oscillator ? { top: 0.05, bottom: 0.3 } : { top: 0.1, bottom: 0.1 },
+    });
+
+    const active: ActiveIndicator[] = overlays
+      .filter((overlay) => chartOverlays.includes(overlay.id))
+      .map((indicator) => ({
+        indicator,
+        series: addIndicatorSeries(chart, indicator, 'right'),
+        stream: null,
+      }));
+
+    if (oscillator) {
+      const series = addIndicatorSeries(chart, oscillator, 'oscillator');
+      chart.priceScale('oscillator').applyOptions({
+        scaleMargins: { top: 0.75, bottom: 0 },
+      });
+      oscillator.levels?.forEach((level) => {
+        series[0].createPriceLine({
+          price: level,
+          color: '#555963',
+          lineWidth: 1,
+          lineStyle: LineStyle.Dashed,
+          axisLabelVisible: false,
+        });
+      });
+      active.push({ indicator: oscillator, series, stream: null });
+    }
+
+    active.forEach((entry) => refreshIndicator(entry, klinesRef.current));
+    refreshMarkers(seriesRef.current, active, klinesRef.current);
+    indicatorsRef.current = active;
+  }, [chartOscillator, chartOverlays]);
 
   return (
     <div className="trading-chart">
@@ -113,6 +290,29 @@ export default function TradingChart() {
             </button>
           ))}
         </div>
+
+        <div className="indicator-selector">
+          {overlays.map((indicator) => (
+            <button
+              key={indicator.id}
+              className={`indicator-btn ${chartOverlays.includes(indicator.id) ? 'active' : ''}`}
+              title={candlesNeeded(indicator)}
+              onClick={() => toggleChartOverlay(indicator.id)}
+            >
+              {indicator.label}
+            </button>
+          ))}
+          {oscillators.map((indicator) => (
+            <button
+              key={indicator.id}
+              className={`indicator-btn ${chartOscillator === indicator.id ? 'active' : ''}`}
+              title={candlesNeeded(indicator)}
+              onClick={() => setChartOscillator(chartOscillator === indicator.id ? null : indicator.id)}
+            >
+              {indicator.label}
+            </button>
+          ))}
+        </div>
       </div>
 
       <div className="chart-container" ref={chartContainerRef}>
@@ -0,0 +1,65 @@
+import { describe, it, expect } from 'vitest';
+import { hasEnoughCandles, oscillators, overlays, seriesMinCandles } from './chartIndicators';
+import { kline } from '../test/kline';
+
+const closes = Array.from({ length: 80 }, (_, i) => 100 + 5 * Math.sin(i / 4) + i * 0.2);
+const candles = closes.map((close, i) => kline({ time: i * 60, close }));
+
+describe('EMA overlay stream', () => {
+  const ema = overlays.find((o) => o.id === 'ema-21-55')!;
+
+  it('tracks the batch EMA through open-candle ticks and closes', () => {
+    const stream = ema.stream!(candles.slice(0, 60));
+
+    for (let i = 59; i < candles.length; i++) {
+      // A few ticks on the open candle before it settles on its close
+      stream.next({ ...candles[i], close: candles[i].close + 3 });
+      const points = stream.next(candles[i]);
+
+      const batch = ema.compute(candles.slice(0, i + 1));
+      points.forEach((point, series) => {
+        const expected = batch[series][batch[series].length - 1];
+        expect(point!.time).toBe(expected.time);
+        expect(Math.abs(point!.value - expected.value)).toBeLessThan(1e-9);
+      });
+    }
+  });
+
+  it('returns nothing for periods still warming up', () => {
+    const stream = ema.stream!(candles.slice(0, 30));
+    const [fast, slow] = stream.next(candles[30]);
+
+    expect(fast).not.toBeNull();
+    expect(slow).toBeNull();
+  });
+});
+
+describe('minimum candle counts', () => {
+  const ema = overlays.find((o) => o.id === 'ema-21-55')!;
+
+  it('gates each EMA line on its own period', () => {
+    expect(hasEnoughCandles(ema, 20)).toBe(false);
+    expect(hasEnoughCandles(ema, 21)).toBe(true);
+    expect(hasEnoughCandles(ema, 21, 0)).toBe(true);
+    expect(hasEnoughCandles(ema, 54, 1)).toBe(false);
+    expect(hasEnoughCandles(ema, 55, 1)).toBe(true);
+  });
+
+  it('gives every series a value once it has its minimum', () => {
+    for (const indicator of [...overlays, ...oscillators]) {
+      indicator.series.forEach((_, i) => {
+        const data = indicator.compute(candles.slice(0, seriesMinCandles(indicator, i)));
+
+        expect(data[i].length).toBeGreaterThan(0);
+      });
+    }
+  });
+
+  it('sets each minimum at the lookback, not below it', () => {
+    const [fast] = ema.compute(candles.slice(0, 20));
+    const [, slow] = ema.compute(candles.slice(0, 54));
+
+    expect(fast).toEqual([]);
+    expect(slow).toEqual([]);
+  });
+});
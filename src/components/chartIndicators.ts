@@ -0,0 +1,258 @@
+import { Kline } from '../types';
+import { Band, calculateBollinger } from '../indicators/bollinger';
+import { calculateEma, EmaState } from '../indicators/ema';
+import { calculateKeltner, calculateSqueeze } from '../indicators/keltner';
+import { calculateMacd } from '../indicators/macd';
+import { calculateMfi } from '../indicators/mfi';
+import { calculateObv, obvDivergences } from '../indicators/obv';
+import { calculateStochastic } from '../indicators/stochastic';
+import { calculateSupertrend } from '../indicators/supertrend';
+import { calculateSessionVwap } from '../indicators/vwap';
+
+export interface IndicatorPoint {
+  time: number;
+  value: number;
+  color?: string;
+}
+
+export interface IndicatorSeries {
+  type: 'line' | 'histogram';
+  color: string;
+  // Set when this series needs more candles than the indicator as a whole
+  minCandles?: number;
+}
+
+export interface IndicatorMarker {
+  time: number;
+  position: 'aboveBar' | 'belowBar';
+  shape: 'arrowUp' | 'arrowDown' | 'circle';
+  color: string;
+  text?: string;
+}
+
+// Keeps an indicator current from the live stream. `next` receives every
+// kline update and returns the latest point of each series.
+export interface IndicatorStream {
+  next: (candle: Kline) => (IndicatorPoint | null)[];
+}
+
+export interface ChartIndicator {
+  id: string;
+  label: string;
+  // Fewest candles for the indicator (and any signal markers) to be
+  // meaningful; below this the chart leaves it empty. Series with a longer
+  // lookback raise their own minimum.
+  minCandles: number;
+  series: IndicatorSeries[];
+  levels?: number[];
+  compute: (candles: Kline[]) => IndicatorPoint[][];
+  // Drawn on the candle series, e.g. trend flips
+  markers?: (candles: Kline[]) => IndicatorMarker[];
+  stream?: (candles: Kline[]) => IndicatorStream;
+}
+
+export function seriesMinCandles(indicator: ChartIndicator, series: number): number {
+  return Math.max(indicator.minCandles, indicator.series[series]?.minCandles ?? 0);
+}
+
+// Checks one series when `series` is given, otherwise the indicator as a whole
+export function hasEnoughCandles(
+  indicator: ChartIndicator,
+  count: number,
+  series?: number
+): boolean {
+  const minCandles = series === undefined ? indicator.minCandles : seriesMinCandles(indicator, series);
+  return count >= minCandles;
+}
+
+export function toPoints(candles: Kline[], values: (number | null)[]): IndicatorPoint[] {
+  const points: IndicatorPoint[] = [];
+  values.forEach((value, i) => {
+    if (value !== null) {
+      points.push({ time: candles[i].time, value });
+    }
+  });
+  return points;
+}
+
+// The last candle in chart history may still be open, so it is peeked rather
+// than committed. A newer time on the stream means it closed.
+function emaStream(candles: Kline[], periods: number[]): IndicatorStream {
+  const states = periods.map((period) => new EmaState(period));
+  const history = candles.slice(0, -1);
+  history.forEach((candle) => states.forEach((state) => state.update(candle.close)));
+
+  let open = candles[candles.length - 1];
+
+  return {
+    next: (candle) => {
+      if (candle.time > open.time) {
+        states.forEach((state) => state.update(open.close));
+      }
+      if (candle.time >= open.time) {
+        open = candle;
+      }
+      return states.map((state) => {
+        const value = state.peek(open.close);
+        return value === null ? null : { time: open.time, value };
+      });
+    },
+  };
+}
+
+function emaOverlay(periods: number[], colors: string[]): ChartIndicator {
+  return {
+    id: `ema-${periods.join('-')}`,
+    label: `EMA ${periods.join('/')}`,
+    minCandles: Math.min(...periods),
+    series: periods.map((period, i) => ({ type: 'line' as const, color: colors[i], minCandles: period })),
+    compute: (candles) => {
+      const closes = candles.map((c) => c.close);
+      return periods.map((period) => toPoints(candles, calculateEma(closes, period)));
+    },
+    stream: (candles) => emaStream(candles, periods),
+  };
+}
+
+function bandPoints(candles: Kline[], bands: (Band | null)[]): IndicatorPoint[][] {
+  return [
+    toPoints(candles, bands.map((band) => band?.upper ?? null)),
+    toPoints(candles, bands.map((band) => band?.lower ?? null)),
+  ];
+}
+
+// Drawn over the candles on the main price scale.
+export const overlays: ChartIndicator[] = [
+  emaOverlay([21, 55], ['#F7A600', '#2962FF']),
+  {
+    id: 'vwap',
+    label: 'VWAP',
+    minCandles: 1,
+    series: [{ type: 'line', color: '#E040FB' }],
+    compute: (candles) => [toPoints(candles, calculateSessionVwap(candles))],
+  },
+  {
+    id: 'supertrend',
+    label: 'Supertrend',
+    minCandles: 10,
+    series: [{ type: 'line', color: '#26A69A' }],
+    compute: (candles) => {
+      const points: IndicatorPoint[] = [];
+      calculateSupertrend(candles, 10, 3).forEach((point, i) => {
+        if (point) {
+          points.push({
+            time: candles[i].time,
+            value: point.value,
+            color: point.direction === 1 ? '#26A69A' : '#EF5350',
+          });
+        }
+      });
+      return [points];
+    },
+    markers: (candles) => {
+      const markers: IndicatorMarker[] = [];
+      calculateSupertrend(candles, 10, 3).forEach((point, i) => {
+        if (point?.flipped) {
+          markers.push(
+            point.direction === 1
+              ? { time: candles[i].time, position: 'belowBar', shape: 'arrowUp', color: '#26A69A' }
+              : { time: candles[i].time, position: 'aboveBar', shape: 'arrowDown', color: '#EF5350' }
+          );
+        }
+      });
+      return markers;
+    },
+  },
+  {
+    id: 'squeeze',
+    label: 'Squeeze',
+    minCandles: 20,
+    series: [
+      { type: 'line', color: '#42A5F5' },
+      { type: 'line', color: '#42A5F5' },
+      { type: 'line', color: '#FFA726' },
+      { type: 'line', color: '#FFA726' },
+    ],
+    compute: (candles) => [
+      ...bandPoints(candles, calculateBollinger(candles, 20, 2)),
+      ...bandPoints(candles, calculateKeltner(candles, 20, 1.5)),
+    ],
+    // Marks where Bollinger Bands move inside the Keltner Channel
+    markers: (candles) => {
+      const markers: IndicatorMarker[] = [];
+      calculateSqueeze(candles, 20).forEach((on, i, all) => {
+        if (on && !all[i - 1]) {
+          markers.push({ time: candles[i].time, position: 'aboveBar', shape: 'circle', color: '#FFA726', text: 'Squeeze' });
+        }
+      });
+      return markers;
+    },
+  },
+];
+
+// Drawn in a separate pane below the candles; one at a time.
+export const oscillators: ChartIndicator[] = [
+  {
+    id: 'mfi',
+    label: 'MFI',
+    minCandles: 15,
+    series: [{ type: 'line', color: '#AB47BC' }],
+    levels: [20, 80],
+    compute: (candles) => [toPoints(candles, calculateMfi(candles, 14))],
+  },
+  {
+    id: 'macd',
+    label: 'MACD',
+    minCandles: 34,
+    series: [
+      { type: 'histogram', color: '#26A69A' },
+      { type: 'line', color: '#2962FF' },
+      { type: 'line', color: '#F7A600' },
+    ],
+    levels: [0],
+    compute: (candles) => {
+      const { macd, signal, histogram } = calculateMacd(candles.map((c) => c.close));
+      const bars = toPoints(candles, histogram).map((point) => ({
+        ...point,
+        color: point.value >= 0 ? '#26A69A80' : '#EF535080',
+      }));
+      return [bars, toPoints(candles, macd), toPoints(candles, signal)];
+    },
+  },
+  {
+    id: 'stoch',
+    label: 'Stoch',
+    minCandles: 16,
+    series: [
+      { type: 'line', color: '#2962FF' },
+      { type: 'line', color: '#F7A600' },
+    ],
+    levels: [20, 80],
+    compute: (candles) => {
+      const { k, d } = calculateStochastic(candles, 14, 3);
+      return [toPoints(candles, k), toPoints(candles, d)];
+    },
+  },
+  {
+    id: 'obv',
+    label: 'OBV',
+    minCandles: 28,
+    series: [{ type: 'line', color: '#26C6DA' }],
+    compute: (candles) => [
+      calculateObv(candles).map((value, i) => ({ time: candles[i].time, value })),
+    ],
+    // Only the candle where a divergence starts is marked
+    markers: (candles) => {
+      const markers: IndicatorMarker[] = [];
+      obvDivergences(candles, 14).forEach((divergence, i, all) => {
+        if (!divergence || divergence === all[i - 1]) return;
+        markers.push(
+          divergence === 'bullish'
+            ? { time: candles[i].time, position: 'belowBar', shape: 'arrowUp', color: '#26C6DA', text: 'OBV' }
+            : { time: candles[i].time, position: 'aboveBar', shape: 'arrowDown', color: '#26C6DA', text: 'OBV' }
+        );
+      });
+      return markers;
+    },
+  },
+];
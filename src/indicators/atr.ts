@@ -0,0 +1,28 @@
+import { Kline } from '../types';
+
+export function trueRange(candle: Kline, previous?: Kline): number {
+  if (!previous) return candle.high - candle.low;
+  return Math.max(
+    candle.high - candle.low,
+    Math.abs(candle.high - previous.close),
+    Math.abs(candle.low - previous.close)
+  );
+}
+
+// Wilder's ATR: seeded with the mean true range of the first `period`
+// candles, then smoothed with alpha = 1 / period.
+export function calculateAtr(candles: Kline[], period: number = 14): (number | null)[] {
+  const atr: (number | null)[] = candles.map(() => null);
+  if (period <= 0 || candles.length < period) return atr;
+
+  const ranges = candles.map((candle, i) => trueRange(candle, candles[i - 1]));
+  let value = ranges.slice(0, period).reduce((sum, v) => sum + v, 0) / period;
+  atr[period - 1] = value;
+
+  for (let i = period; i < candles.length; i++) {
+    value = (value * (period - 1) + ranges[i]) / period;
+    atr[i] = value;
+  }
+
+  return atr;
+}
@@ -0,0 +1,25 @@
+import { Kline } from '../types';
+
+export interface Band {
+  middle: number;
+  upper: number;
+  lower: number;
+}
+
+// SMA middle band with upper/lower bands `deviations` population standard
+// deviations of the close away.
+export function calculateBollinger(
+  candles: Kline[],
+  period: number = 20,
+  deviations: number = 2
+): (Band | null)[] {
+  return candles.map((_, i) => {
+    if (i < period - 1) return null;
+
+    const closes = candles.slice(i - period + 1, i + 1).map((c) => c.close);
+    const middle = closes.reduce((sum, v) => sum + v, 0) / period;
+    const variance = closes.reduce((sum, v) => sum + (v - middle) ** 2, 0) / period;
+    const offset = deviations * Math.sqrt(variance);
+    return { middle, upper: middle + offset, lower: middle - offset };
+  });
+}
@@ -0,0 +1,53 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { capWick, sanitizeCandles } from './candles';
+import { kline } from '../test/kline';
+
+beforeEach(() => {
+  vi.spyOn(console, 'warn').mockImplementation(() => {});
+});
+
+afterEach(() => {
+  vi.restoreAllMocks();
+});
+
+describe('capWick', () => {
+  it('passes a normal candle through untouched', () => {
+    const normal = kline({ high: 105, low: 96 });
+
+    expect(capWick(normal, 20)).toBe(normal);
+    expect(console.warn).not.toHaveBeenCalled();
+  });
+
+  it('caps a glitch wick to the limit around the close', () => {
+    const capped = capWick(kline({ high: 500, low: 1 }), 20);
+
+    expect(capped.high).toBeCloseTo(120);
+    expect(capped.low).toBeCloseTo(80);
+    expect(console.warn).toHaveBeenCalledTimes(1);
+  });
+
+  it('never cuts into the body', () => {
+    const capped = capWick(kline({ high: 200, low: 60, open: 130 }), 20);
+
+    expect(capped.high).toBe(130);
+    expect(capped.low).toBeCloseTo(80);
+    expect(capped.high).toBeGreaterThanOrEqual(Math.max(capped.open, capped.close));
+    expect(capped.low).toBeLessThanOrEqual(Math.min(capped.open, capped.close));
+  });
+});
+
+describe('sanitizeCandles', () => {
+  it('only rewrites the suspect candles', () => {
+    const candles = [
+      kline({ high: 101, low: 99 }),
+      kline({ high: 1000, low: 99 }),
+      kline({ high: 102, low: 98 }),
+    ];
+
+    const sanitized = sanitizeCandles(candles, 20);
+
+    expect(sanitized[0]).toBe(candles[0]);
+    expect(sanitized[1].high).toBeCloseTo(120);
+    expect(sanitized[2]).toBe(candles[2]);
+  });
+});
@@ -0,0 +1,29 @@
+import { Kline } from '../types';
+
+const MAX_WICK_PCT = parseFloat(import.meta.env.VITE_MAX_WICK_PCT ?? '') || 20;
+
+// Caps a wick that strays more than maxWickPct from the close. The body is
+// never cut, so the capped candle still has high >= open/close >= low.
+export function capWick(candle: Kline, maxWickPct: number = MAX_WICK_PCT): Kline {
+  const ceiling = Math.max(candle.open, candle.close * (1 + maxWickPct / 100));
+  const floor = Math.min(candle.open, candle.close * (1 - maxWickPct / 100));
+
+  if (candle.high <= ceiling && candle.low >= floor) {
+    return candle;
+  }
+
+  const capped = {
+    ...candle,
+    high: Math.min(candle.high, ceiling),
+    low: Math.max(candle.low, floor),
+  };
+  console.warn(
+    `Capped suspect candle at ${candle.time}: high ${candle.high} -> ${capped.high}, ` +
+      `low ${candle.low} -> ${capped.low} (close ${candle.close}, max wick ${maxWickPct}%)`
+  );
+  return capped;
+}
+
+export function sanitizeCandles(candles: Kline[], maxWickPct: number = MAX_WICK_PCT): Kline[] {
+  return candles.map((candle) => capWick(candle, maxWickPct));
+}
@@ -0,0 +1,56 @@
+import { describe, it, expect } from 'vitest';
+import { calculateEma, EmaState } from './ema';
+
+const closes = Array.from({ length: 120 }, (_, i) => 100 + 10 * Math.sin(i / 7) + i * 0.3);
+
+describe('calculateEma', () => {
+  it('seeds from the SMA of the first period', () => {
+    const ema = calculateEma([1, 2, 3, 4, 5], 3);
+
+    expect(ema.slice(0, 2)).toEqual([null, null]);
+    expect(ema[2]).toBeCloseTo(2);
+    expect(ema[3]).toBeCloseTo(3);
+    expect(ema[4]).toBeCloseTo(4);
+  });
+
+  it('returns only nulls when there are fewer values than the period', () => {
+    expect(calculateEma([1, 2], 3)).toEqual([null, null]);
+  });
+});
+
+describe('EmaState', () => {
+  it('matches the batch EMA after warmup', () => {
+    for (const period of [9, 21, 55]) {
+      const batch = calculateEma(closes, period);
+      const state = new EmaState(period);
+
+      closes.forEach((close, i) => {
+        const value = state.update(close);
+        if (batch[i] === null) {
+          expect(value).toBeNull();
+        } else {
+          expect(Math.abs((value as number) - (batch[i] as number))).toBeLessThan(1e-9);
+        }
+      });
+    }
+  });
+
+  it('peeks without committing', () => {
+    const state = new EmaState(3);
+    [1, 2, 3].forEach((v) => state.update(v));
+
+    expect(state.peek(10)).toBeCloseTo(6);
+    expect(state.peek(10)).toBeCloseTo(6);
+    expect(state.value).toBeCloseTo(2);
+    expect(state.update(10)).toBeCloseTo(6);
+  });
+
+  it('peeks the seed value on the last warmup sample', () => {
+    const state = new EmaState(3);
+    state.update(1);
+
+    expect(state.peek(2)).toBeNull();
+    state.update(2);
+    expect(state.peek(3)).toBeCloseTo(2);
+  });
+});
@@ -0,0 +1,50 @@
+export function calculateEma(values: number[], period: number): (number | null)[] {
+  const ema: (number | null)[] = values.map(() => null);
+  if (period <= 0 || values.length < period) return ema;
+
+  const alpha = 2 / (period + 1);
+  let value = values.slice(0, period).reduce((sum, v) => sum + v, 0) / period;
+  ema[period - 1] = value;
+
+  for (let i = period; i < values.length; i++) {
+    value = alpha * values[i] + (1 - alpha) * value;
+    ema[i] = value;
+  }
+
+  return ema;
+}
+
+// Streaming EMA fed one closed candle at a time, so a live chart doesn't
+// recompute the whole series for every tick. Seeds from an SMA of the first
+// `period` samples, matching calculateEma.
+export class EmaState {
+  readonly period: number;
+  value: number | null = null;
+  private readonly alpha: number;
+  private seed: number[] = [];
+
+  constructor(period: number) {
+    this.period = period;
+    this.alpha = 2 / (period + 1);
+  }
+
+  update(price: number): number | null {
+    this.value = this.peek(price);
+    if (this.value === null) {
+      this.seed.push(price);
+    } else {
+      this.seed = [];
+    }
+    return this.value;
+  }
+
+  // Value the EMA would take if `price` closed now, without committing it.
+  // Used for the still-open candle.
+  peek(price: number): number | null {
+    if (this.value !== null) {
+      return this.alpha * price + (1 - this.alpha) * this.value;
+    }
+    if (this.seed.length + 1 < this.period) return null;
+    return (this.seed.reduce((sum, v) => sum + v, 0) + price) / this.period;
+  }
+}
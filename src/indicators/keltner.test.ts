@@ -0,0 +1,49 @@
+import { describe, it, expect } from 'vitest';
+import { calculateBollinger } from './bollinger';
+import { calculateKeltner, calculateSqueeze } from './keltner';
+import { kline } from '../test/kline';
+
+describe('calculateBollinger', () => {
+  it('uses the population standard deviation of the closes', () => {
+    const candles = [2, 4, 4, 4, 5, 5, 7, 9].map((close, i) => kline({ time: i, close }));
+    const band = calculateBollinger(candles, 8, 2)[7]!;
+
+    expect(band.middle).toBeCloseTo(5);
+    expect(band.upper).toBeCloseTo(9);
+    expect(band.lower).toBeCloseTo(1);
+  });
+});
+
+describe('calculateKeltner', () => {
+  it('puts the bands a multiple of ATR around the EMA', () => {
+    const candles = Array.from({ length: 20 }, (_, i) => kline({ time: i, close: 100 }, 2));
+    const band = calculateKeltner(candles, 20, 1.5)[19]!;
+
+    expect(band.middle).toBeCloseTo(100);
+    expect(band.upper).toBeCloseTo(106);
+    expect(band.lower).toBeCloseTo(94);
+  });
+});
+
+describe('calculateSqueeze', () => {
+  it('is on when tight closes sit inside wide candle ranges', () => {
+    // Closes barely move but every candle has a wide range
+    const candles = Array.from({ length: 30 }, (_, i) =>
+      kline({ time: i, close: 100 + (i % 2) * 0.2 }, 3)
+    );
+
+    expect(calculateSqueeze(candles)[29]).toBe(true);
+  });
+
+  it('is off when a trend spreads closes wider than the candle ranges', () => {
+    const candles = Array.from({ length: 30 }, (_, i) => kline({ time: i, close: 100 + i }, 0.5));
+
+    expect(calculateSqueeze(candles)[29]).toBe(false);
+  });
+
+  it('is null until both bands are available', () => {
+    const candles = Array.from({ length: 19 }, (_, i) => kline({ time: i, close: 100 }));
+
+    expect(calculateSqueeze(candles).every((squeeze) => squeeze === null)).toBe(true);
+  });
+});
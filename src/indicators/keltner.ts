@@ -0,0 +1,35 @@
+import { Kline } from '../types';
+import { calculateAtr } from './atr';
+import { Band, calculateBollinger } from './bollinger';
+import { calculateEma } from './ema';
+
+// EMA midline with bands `multiplier` ATRs away.
+export function calculateKeltner(
+  candles: Kline[],
+  period: number = 20,
+  multiplier: number = 1.5
+): (Band | null)[] {
+  const ema = calculateEma(candles.map((c) => c.close), period);
+  const atr = calculateAtr(candles, period);
+
+  return candles.map((_, i) => {
+    const middle = ema[i];
+    const range = atr[i];
+    if (middle === null || range === null) return null;
+    return { middle, upper: middle + multiplier * range, lower: middle - multiplier * range };
+  });
+}
+
+// TTM squeeze: on while the Bollinger Bands sit inside the Keltner Channel.
+// Null until both are available.
+export function calculateSqueeze(candles: Kline[], period: number = 20): (boolean | null)[] {
+  const bollinger = calculateBollinger(candles, period, 2);
+  const keltner = calculateKeltner(candles, period, 1.5);
+
+  return candles.map((_, i) => {
+    const bb = bollinger[i];
+    const kc = keltner[i];
+    if (!bb || !kc) return null;
+    return bb.upper < kc.upper && bb.lower > kc.lower;
+  });
+}
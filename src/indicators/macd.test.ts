@@ -0,0 +1,40 @@
+import { describe, it, expect } from 'vitest';
+import { calculateMacd } from './macd';
+
+describe('calculateMacd', () => {
+  it('matches the closed form on a linear ramp', () => {
+    // On a ramp an SMA-seeded EMA lags by exactly (period - 1) / 2, so
+    // MACD(12, 26) is (26 - 12) / 2 = 7 and the histogram is flat at zero.
+    const closes = Array.from({ length: 60 }, (_, i) => i);
+    const { macd, signal, histogram } = calculateMacd(closes, 12, 26, 9);
+
+    expect(macd[24]).toBeNull();
+    expect(macd[25]).toBeCloseTo(7, 9);
+    expect(signal[32]).toBeNull();
+    expect(signal[33]).toBeCloseTo(7, 9);
+    expect(histogram[32]).toBeNull();
+    for (let i = 33; i < closes.length; i++) {
+      expect(histogram[i]).toBeCloseTo(0, 9);
+    }
+  });
+
+  it('crosses below zero on a selloff and back above on the recovery', () => {
+    const closes = [
+      ...Array.from({ length: 40 }, () => 100),
+      ...Array.from({ length: 20 }, (_, i) => 100 - i),
+      ...Array.from({ length: 30 }, (_, i) => 81 + 2 * i),
+    ];
+    const { histogram } = calculateMacd(closes);
+
+    expect(histogram[50]!).toBeLessThan(0);
+    expect(histogram[closes.length - 1]!).toBeGreaterThan(0);
+  });
+
+  it('returns only nulls without enough closes', () => {
+    const { macd, signal, histogram } = calculateMacd([1, 2, 3], 12, 26, 9);
+
+    expect(macd).toEqual([null, null, null]);
+    expect(signal).toEqual([null, null, null]);
+    expect(histogram).toEqual([null, null, null]);
+  });
+});
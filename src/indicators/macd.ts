@@ -0,0 +1,40 @@
+import { calculateEma } from './ema';
+
+export interface MacdOutput {
+  macd: (number | null)[];
+  signal: (number | null)[];
+  histogram: (number | null)[];
+}
+
+// All three series are aligned with `closes`. The MACD line starts once the
+// slow EMA is warm; the signal and histogram need `signal` more closes.
+export function calculateMacd(
+  closes: number[],
+  fast: number = 12,
+  slow: number = 26,
+  signal: number = 9
+): MacdOutput {
+  const fastEma = calculateEma(closes, fast);
+  const slowEma = calculateEma(closes, slow);
+  const macd = closes.map((_, i) => {
+    const f = fastEma[i];
+    const s = slowEma[i];
+    return f === null || s === null ? null : f - s;
+  });
+
+  const start = macd.findIndex((value) => value !== null);
+  const signalLine: (number | null)[] = closes.map(() => null);
+  if (start !== -1) {
+    const tail = calculateEma(macd.slice(start) as number[], signal);
+    tail.forEach((value, i) => {
+      signalLine[start + i] = value;
+    });
+  }
+
+  const histogram = macd.map((value, i) => {
+    const s = signalLine[i];
+    return value === null || s === null ? null : value - s;
+  });
+
+  return { macd, signal: signalLine, histogram };
+}
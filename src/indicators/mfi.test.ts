@@ -0,0 +1,41 @@
+import { describe, it, expect } from 'vitest';
+import { calculateMfi } from './mfi';
+import { kline } from '../test/kline';
+
+const series = (closes: number[]) =>
+  closes.map((close, i) => kline({ time: i, close, volume: 100 }));
+
+describe('calculateMfi', () => {
+  it('returns nothing until the period is filled', () => {
+    const values = calculateMfi(series([10, 11, 12]), 3);
+
+    expect(values).toEqual([null, null, null]);
+  });
+
+  it('reads oversold on a falling series', () => {
+    const closes = [100, 99, 98, 97, 96, 95, 96, 94, 93, 92, 91, 90, 89, 88, 87, 86];
+    const values = calculateMfi(series(closes), 14);
+
+    expect(values[15]).not.toBeNull();
+    expect(values[15]!).toBeLessThan(20);
+  });
+
+  it('reads overbought on a rising series', () => {
+    const closes = [100, 101, 102, 103, 104, 105, 104, 106, 107, 108, 109, 110, 111, 112, 113, 114];
+    const values = calculateMfi(series(closes), 14);
+
+    expect(values[15]!).toBeGreaterThan(80);
+  });
+
+  it('handles a window with no negative flow', () => {
+    const closes = Array.from({ length: 16 }, (_, i) => 100 + i);
+
+    expect(calculateMfi(series(closes), 14)[15]).toBe(100);
+  });
+
+  it('is neutral when the typical price never moves', () => {
+    const closes = Array.from({ length: 16 }, () => 100);
+
+    expect(calculateMfi(series(closes), 14)[15]).toBe(50);
+  });
+});
@@ -0,0 +1,30 @@
+import { Kline } from '../types';
+
+export function calculateMfi(candles: Kline[], period: number = 14): (number | null)[] {
+  const values: (number | null)[] = candles.map(() => null);
+  const typical = candles.map(c => (c.high + c.low + c.close) / 3);
+
+  for (let i = period; i < candles.length; i++) {
+    let positive = 0;
+    let negative = 0;
+
+    for (let j = i - period + 1; j <= i; j++) {
+      const flow = typical[j] * candles[j].volume;
+      if (typical[j] > typical[j - 1]) {
+        positive += flow;
+      } else if (typical[j] < typical[j - 1]) {
+        negative += flow;
+      }
+    }
+
+    if (negative === 0) {
+      // All-up window is maximally overbought; no flow at all is neutral
+      values[i] = positive === 0 ? 50 : 100;
+    } else {
+      const mfi = 100 - 100 / (1 + positive / negative);
+      values[i] = Math.min(100, Math.max(0, mfi));
+    }
+  }
+
+  return values;
+}
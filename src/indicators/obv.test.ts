@@ -0,0 +1,56 @@
+import { describe, it, expect } from 'vitest';
+import { calculateObv, obvDivergence, obvDivergences } from './obv';
+import { kline } from '../test/kline';
+
+const build = (bars: [number, number][]) =>
+  bars.map(([close, volume], i) => kline({ time: i * 60, close, volume }));
+
+describe('calculateObv', () => {
+  it('rises monotonically on rising closes', () => {
+    const obv = calculateObv(build(Array.from({ length: 20 }, (_, i) => [100 + i, 5 + i])));
+
+    for (let i = 1; i < obv.length; i++) {
+      expect(obv[i]).toBeGreaterThan(obv[i - 1]);
+    }
+  });
+
+  it('signs volume by close-to-close direction', () => {
+    const obv = calculateObv(build([[10, 1], [11, 4], [9, 3], [9, 7], [12, 2]]));
+
+    expect(obv).toEqual([0, 4, 1, 1, 3]);
+  });
+});
+
+describe('obvDivergence', () => {
+  it('flags a higher high on weakening volume as bearish', () => {
+    // Strong rally to 104, then a heavy drop and a thin push to a new high
+    const candles = build([
+      [100, 10], [102, 10], [104, 10], [103, 1],
+      [101, 20], [103, 1], [105, 1], [104, 1],
+    ]);
+
+    expect(obvDivergence(candles, 4)).toBe('bearish');
+  });
+
+  it('flags a lower low on strengthening volume as bullish', () => {
+    const candles = build([
+      [100, 10], [98, 10], [96, 10], [97, 1],
+      [99, 20], [97, 1], [95, 1], [96, 1],
+    ]);
+
+    expect(obvDivergence(candles, 4)).toBe('bullish');
+  });
+
+  it('finds nothing when volume confirms the move', () => {
+    const candles = build(Array.from({ length: 8 }, (_, i) => [100 + i, 10]));
+
+    expect(obvDivergence(candles, 4)).toBeNull();
+  });
+
+  it('needs two full lookback windows', () => {
+    const candles = build([[100, 1], [101, 1], [102, 1]]);
+
+    expect(obvDivergence(candles, 2)).toBeNull();
+    expect(obvDivergences(candles, 2)).toEqual([null, null, null]);
+  });
+});
@@ -0,0 +1,55 @@
+import { Kline } from '../types';
+
+export type Divergence = 'bullish' | 'bearish';
+
+// On-Balance Volume: adds the candle's volume on an up close, subtracts it
+// on a down close, carries it on an unchanged close.
+export function calculateObv(candles: Kline[]): number[] {
+  let obv = 0;
+  return candles.map((candle, i) => {
+    const previous = candles[i - 1];
+    if (previous && candle.close > previous.close) obv += candle.volume;
+    else if (previous && candle.close < previous.close) obv -= candle.volume;
+    return obv;
+  });
+}
+
+// Compares the `lookback` candles ending at `end` with the `lookback` before
+// them: a higher price high on a lower OBV high is bearish, a lower price low
+// on a higher OBV low is bullish.
+function divergenceAt(
+  candles: Kline[],
+  obv: number[],
+  end: number,
+  lookback: number
+): Divergence | null {
+  const start = end - 2 * lookback + 1;
+  if (lookback <= 0 || start < 0) return null;
+
+  const range = (from: number, to: number) => {
+    const prices = candles.slice(from, to);
+    const volumes = obv.slice(from, to);
+    return {
+      high: Math.max(...prices.map((c) => c.high)),
+      low: Math.min(...prices.map((c) => c.low)),
+      obvHigh: Math.max(...volumes),
+      obvLow: Math.min(...volumes),
+    };
+  };
+  const prior = range(start, start + lookback);
+  const recent = range(start + lookback, end + 1);
+
+  if (recent.high > prior.high && recent.obvHigh < prior.obvHigh) return 'bearish';
+  if (recent.low < prior.low && recent.obvLow > prior.obvLow) return 'bullish';
+  return null;
+}
+
+export function obvDivergence(candles: Kline[], lookback: number): Divergence | null {
+  return divergenceAt(candles, calculateObv(candles), candles.length - 1, lookback);
+}
+
+// Divergence as of each candle, aligned with `candles`.
+export function obvDivergences(candles: Kline[], lookback: number): (Divergence | null)[] {
+  const obv = calculateObv(candles);
+  return candles.map((_, i) => divergenceAt(candles, obv, i, lookback));
+}
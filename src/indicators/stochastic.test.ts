@@ -0,0 +1,40 @@
+import { describe, it, expect } from 'vitest';
+import { calculateStochastic } from './stochastic';
+import { kline } from '../test/kline';
+
+describe('calculateStochastic', () => {
+  it('places the close within the rolling range', () => {
+    const candles = [
+      kline({ high: 10, low: 0, close: 5 }),
+      kline({ high: 12, low: 4, close: 6 }),
+      kline({ high: 11, low: 2, close: 9 }),
+    ];
+    const { k } = calculateStochastic(candles, 3, 1);
+
+    expect(k.slice(0, 2)).toEqual([null, null]);
+    expect(k[2]).toBeCloseTo(75);
+  });
+
+  it('smooths %K into %D', () => {
+    const candles = [
+      kline({ high: 10, low: 0, close: 0 }),
+      kline({ high: 10, low: 0, close: 5 }),
+      kline({ high: 10, low: 0, close: 10 }),
+      kline({ high: 10, low: 0, close: 6 }),
+    ];
+    const { k, d } = calculateStochastic(candles, 1, 3);
+
+    expect(k).toEqual([0, 50, 100, 60]);
+    expect(d[1]).toBeNull();
+    expect(d[2]).toBeCloseTo(50);
+    expect(d[3]).toBeCloseTo(70);
+  });
+
+  it('reads a neutral 50 on a flat window', () => {
+    const flat = Array.from({ length: 5 }, () => kline({ high: 100, low: 100, close: 100 }));
+    const { k, d } = calculateStochastic(flat, 3, 3);
+
+    expect(k.slice(2)).toEqual([50, 50, 50]);
+    expect(d[4]).toBe(50);
+  });
+});
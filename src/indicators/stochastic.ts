@@ -0,0 +1,31 @@
+import { Kline } from '../types';
+
+export interface StochasticOutput {
+  k: (number | null)[];
+  d: (number | null)[];
+}
+
+// %K is the close's position in the rolling high/low range; %D is the SMA
+// of %K. A flat window (high == low) reads a neutral 50.
+export function calculateStochastic(
+  candles: Kline[],
+  kPeriod: number = 14,
+  dPeriod: number = 3
+): StochasticOutput {
+  const k: (number | null)[] = candles.map(() => null);
+  const d: (number | null)[] = candles.map(() => null);
+
+  for (let i = kPeriod - 1; i < candles.length; i++) {
+    const window = candles.slice(i - kPeriod + 1, i + 1);
+    const high = Math.max(...window.map((c) => c.high));
+    const low = Math.min(...window.map((c) => c.low));
+    k[i] = high === low ? 50 : ((candles[i].close - low) / (high - low)) * 100;
+
+    if (i >= kPeriod + dPeriod - 2) {
+      const recent = k.slice(i - dPeriod + 1, i + 1) as number[];
+      d[i] = recent.reduce((sum, v) => sum + v, 0) / dPeriod;
+    }
+  }
+
+  return { k, d };
+}
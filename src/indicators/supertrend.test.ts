@@ -0,0 +1,68 @@
+import { describe, it, expect } from 'vitest';
+import { calculateAtr } from './atr';
+import { calculateSupertrend } from './supertrend';
+import { kline } from '../test/kline';
+
+const fromCloses = (closes: number[]) =>
+  closes.map((close, i) => {
+    const open = i === 0 ? close : closes[i - 1];
+    return kline({
+      time: i * 60,
+      open,
+      high: Math.max(open, close) + 0.5,
+      low: Math.min(open, close) - 0.5,
+      close,
+    });
+  });
+
+const ramp = (from: number, step: number, length: number) =>
+  Array.from({ length }, (_, i) => from + step * (i + 1));
+
+describe('calculateAtr', () => {
+  it('seeds with the mean range then applies Wilder smoothing', () => {
+    const candles = fromCloses([10, 10, 10, 14]);
+    const atr = calculateAtr(candles, 3);
+
+    expect(atr.slice(0, 2)).toEqual([null, null]);
+    expect(atr[2]).toBeCloseTo(1);
+    // True range of the gap candle is 14.5 - 9.5 = 5
+    expect(atr[3]).toBeCloseTo((1 * 2 + 5) / 3);
+  });
+});
+
+describe('calculateSupertrend', () => {
+  const closes = [...ramp(100, -1, 30), ...ramp(70, 1, 40), ...ramp(110, -1, 40)];
+  const points = calculateSupertrend(fromCloses(closes), 10, 3);
+
+  it('flips exactly once in each direction on a down-up-down series', () => {
+    const flips = points.filter((p) => p?.flipped).map((p) => p!.direction);
+
+    expect(flips).toEqual([1, -1]);
+  });
+
+  it('starts bearish and ends bearish', () => {
+    expect(points[9]!.direction).toBe(-1);
+    expect(points[points.length - 1]!.direction).toBe(-1);
+  });
+
+  it('only moves the trailing band in the trend direction', () => {
+    for (let i = 1; i < points.length; i++) {
+      const prev = points[i - 1];
+      const point = points[i];
+      if (!prev || !point || point.flipped) continue;
+
+      if (point.direction === 1) {
+        expect(point.lower).toBeGreaterThanOrEqual(prev.lower);
+      } else {
+        expect(point.upper).toBeLessThanOrEqual(prev.upper);
+      }
+    }
+  });
+
+  it('records the close at the flip', () => {
+    const flip = points.findIndex((p) => p?.flipped);
+
+    expect(points[flip]!.flipPrice).toBe(closes[flip]);
+    expect(points[flip + 1]!.flipPrice).toBe(closes[flip]);
+  });
+});
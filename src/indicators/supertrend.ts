@@ -0,0 +1,59 @@
+import { Kline } from '../types';
+import { calculateAtr } from './atr';
+
+export interface SupertrendPoint {
+  upper: number;
+  lower: number;
+  direction: 1 | -1;
+  // The band price is trailing: lower in an uptrend, upper in a downtrend
+  value: number;
+  flipped: boolean;
+  flipPrice: number | null;
+}
+
+export function calculateSupertrend(
+  candles: Kline[],
+  period: number = 10,
+  multiplier: number = 3
+): (SupertrendPoint | null)[] {
+  const atr = calculateAtr(candles, period);
+  const points: (SupertrendPoint | null)[] = candles.map(() => null);
+  let previous: SupertrendPoint | null = null;
+
+  candles.forEach((candle, i) => {
+    const range = atr[i];
+    if (range === null) return;
+
+    const mid = (candle.high + candle.low) / 2;
+    let upper = mid + multiplier * range;
+    let lower = mid - multiplier * range;
+    let direction: 1 | -1 = candle.close >= mid ? 1 : -1;
+
+    if (previous) {
+      const prevClose = candles[i - 1].close;
+      // Bands only move in the trend's favour until price closes through them
+      if (upper > previous.upper && prevClose <= previous.upper) upper = previous.upper;
+      if (lower < previous.lower && prevClose >= previous.lower) lower = previous.lower;
+
+      if (previous.direction === 1) {
+        direction = candle.close < lower ? -1 : 1;
+      } else {
+        direction = candle.close > upper ? 1 : -1;
+      }
+    }
+
+    const flipped = previous !== null && direction !== previous.direction;
+    const point: SupertrendPoint = {
+      upper,
+      lower,
+      direction,
+      value: direction === 1 ? lower : upper,
+      flipped,
+      flipPrice: flipped ? candle.close : previous?.flipPrice ?? null,
+    };
+    points[i] = point;
+    previous = point;
+  });
+
+  return points;
+}
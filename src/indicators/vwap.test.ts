@@ -0,0 +1,67 @@
+import { describe, it, expect } from 'vitest';
+import { calculateAnchoredVwap, calculateSessionVwap, calculateVwap } from './vwap';
+import { kline } from '../test/kline';
+
+describe('calculateVwap', () => {
+  it('weights typical price by volume', () => {
+    const vwap = calculateVwap([
+      kline({ time: 0, close: 100, volume: 1 }),
+      kline({ time: 60, close: 110, volume: 3 }),
+    ]);
+
+    expect(vwap).toBeCloseTo(107.5);
+  });
+
+  it('returns null for no candles', () => {
+    expect(calculateVwap([])).toBeNull();
+  });
+
+  it('returns null when nothing traded', () => {
+    const idle = [
+      kline({ time: 0, close: 100, volume: 0 }),
+      kline({ time: 60, close: 110, volume: 0 }),
+    ];
+
+    expect(calculateVwap(idle)).toBeNull();
+  });
+});
+
+describe('calculateAnchoredVwap', () => {
+  it('ignores candles before the anchor', () => {
+    const candles = [
+      kline({ time: 0, close: 50, volume: 100 }),
+      kline({ time: 60, close: 100, volume: 1 }),
+      kline({ time: 120, close: 110, volume: 3 }),
+    ];
+
+    expect(calculateAnchoredVwap(candles, 60)).toBeCloseTo(107.5);
+  });
+
+  it('returns null when the anchor is past the last candle', () => {
+    expect(calculateAnchoredVwap([kline({ time: 0, close: 100, volume: 1 })], 60)).toBeNull();
+  });
+});
+
+describe('calculateSessionVwap', () => {
+  it('restarts at the UTC day boundary', () => {
+    const candles = [
+      kline({ time: 86400 - 60, close: 100, volume: 1 }),
+      kline({ time: 86400, close: 200, volume: 1 }),
+      kline({ time: 86460, close: 220, volume: 1 }),
+    ];
+    const vwap = calculateSessionVwap(candles);
+
+    expect(vwap[0]).toBeCloseTo(100);
+    expect(vwap[1]).toBeCloseTo(200);
+    expect(vwap[2]).toBeCloseTo(210);
+  });
+
+  it('stays null until the session has volume', () => {
+    const vwap = calculateSessionVwap([
+      kline({ time: 0, close: 100, volume: 0 }),
+      kline({ time: 60, close: 110, volume: 2 }),
+    ]);
+
+    expect(vwap).toEqual([null, 110]);
+  });
+});
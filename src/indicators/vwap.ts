@@ -0,0 +1,40 @@
+import { Kline } from '../types';
+
+const SECONDS_PER_DAY = 86400;
+
+const typicalPrice = (c: Kline) => (c.high + c.low + c.close) / 3;
+
+// Volume-weighted typical price. Null when there is no volume to weight by.
+export function calculateVwap(candles: Kline[]): number | null {
+  let priceVolume = 0;
+  let volume = 0;
+  for (const candle of candles) {
+    priceVolume += typicalPrice(candle) * candle.volume;
+    volume += candle.volume;
+  }
+  return volume > 0 ? priceVolume / volume : null;
+}
+
+// VWAP accumulated only from the candle opening at `anchorTime` onwards.
+export function calculateAnchoredVwap(candles: Kline[], anchorTime: number): number | null {
+  return calculateVwap(candles.filter((c) => c.time >= anchorTime));
+}
+
+// Running VWAP that restarts at each UTC day, aligned with `candles`.
+export function calculateSessionVwap(candles: Kline[]): (number | null)[] {
+  let session = -1;
+  let priceVolume = 0;
+  let volume = 0;
+
+  return candles.map((candle) => {
+    const day = Math.floor(candle.time / SECONDS_PER_DAY);
+    if (day !== session) {
+      session = day;
+      priceVolume = 0;
+      volume = 0;
+    }
+    priceVolume += typicalPrice(candle) * candle.volume;
+    volume += candle.volume;
+    return volume > 0 ? priceVolume / volume : null;
+  });
+}
@@ -0,0 +1,369 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import axios from 'axios';
+import {
+  BinanceService,
+  StreamStatus,
+  defaultWsUrl,
+  maintenanceNoGoReason,
+  parseMaxReconnects,
+  parseSystemStatus,
+  shouldReconnect,
+} from './binance';
+
+class FakeWebSocket {
+  static OPEN = 1;
+  static instances: FakeWebSocket[] = [];
+
+  url: string;
+  readyState = 0;
+  onopen: (() => void) | null = null;
+  onclose: (() => void) | null = null;
+  onmessage: ((event: { data: string }) => void) | null = null;
+  onerror: ((error: unknown) => void) | null = null;
+
+  constructor(url: string) {
+    this.url = url;
+    FakeWebSocket.instances.push(this);
+  }
+
+  send() {}
+
+  close() {
+    this.readyState = 3;
+  }
+}
+
+const latestSocket = () => FakeWebSocket.instances[FakeWebSocket.instances.length - 1];
+
+describe('parseMaxReconnects', () => {
+  it('defaults to infinite retries', () => {
+    expect(parseMaxReconnects(undefined)).toBe(0);
+    expect(parseMaxReconnects('abc')).toBe(0);
+  });
+
+  it('clamps negative values to infinite retries', () => {
+    expect(parseMaxReconnects('-1')).toBe(0);
+  });
+
+  it('accepts a positive limit', () => {
+    expect(parseMaxReconnects('5')).toBe(5);
+  });
+});
+
+describe('shouldReconnect', () => {
+  it('always reconnects when the limit is 0', () => {
+    expect(shouldReconnect(0, 0)).toBe(true);
+    expect(shouldReconnect(1000, 0)).toBe(true);
+  });
+
+  it('gives up once the failure count reaches the limit', () => {
+    expect(shouldReconnect(2, 3)).toBe(true);
+    expect(shouldReconnect(3, 3)).toBe(false);
+  });
+});
+
+describe('BinanceService stream reconnects', () => {
+  beforeEach(() => {
+    FakeWebSocket.instances = [];
+    vi.stubGlobal('WebSocket', FakeWebSocket);
+    vi.useFakeTimers();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it('marks the stream dead after the configured failures', async () => {
+    const service = new BinanceService('https://api.binance.com', 'wss://stream.binance.com:9443/ws', 2);
+    const statuses: StreamStatus[] = [];
+    service.subscribeToStatus((status) => statuses.push(status));
+
+    service.resetStream();
+    latestSocket().onclose?.();
+    await vi.advanceTimersByTimeAsync(5000);
+    latestSocket().onclose?.();
+    await vi.advanceTimersByTimeAsync(5000);
+    latestSocket().onclose?.();
+
+    const status = service.getStreamStatus();
+    expect(status.dead).toBe(true);
+    expect(status.noGoReason).toContain('2 failed reconnects');
+    expect(statuses[statuses.length - 1].dead).toBe(true);
+
+    await vi.advanceTimersByTimeAsync(60000);
+    expect(FakeWebSocket.instances).toHaveLength(3);
+  });
+
+  it('resumes after an operator reset', async () => {
+    const service = new BinanceService('https://api.binance.com', 'wss://stream.binance.com:9443/ws', 1);
+
+    service.resetStream();
+    latestSocket().onclose?.();
+    await vi.advanceTimersByTimeAsync(5000);
+    latestSocket().onclose?.();
+    expect(service.getStreamStatus().dead).toBe(true);
+
+    service.resetStream();
+    expect(service.getStreamStatus().dead).toBe(false);
+    expect(FakeWebSocket.instances).toHaveLength(3);
+  });
+
+  it('cancels a pending reconnect when reset', async () => {
+    const service = new BinanceService('https://api.binance.com', 'wss://stream.binance.com:9443/ws', 0);
+
+    service.resetStream();
+    latestSocket().onclose?.();
+    service.resetStream();
+    await vi.advanceTimersByTimeAsync(10000);
+
+    expect(FakeWebSocket.instances).toHaveLength(2);
+  });
+
+  it('does not reconnect after disconnect', async () => {
+    const service = new BinanceService('https://api.binance.com', 'wss://stream.binance.com:9443/ws', 0);
+
+    service.resetStream();
+    latestSocket().onclose?.();
+    service.disconnect();
+    await vi.advanceTimersByTimeAsync(10000);
+
+    expect(FakeWebSocket.instances).toHaveLength(1);
+  });
+});
+
+const httpError = (status: number, headers: Record<string, string> = {}) => ({
+  isAxiosError: true,
+  response: { status, headers },
+});
+
+const ticker = { symbol: 'BTCUSDT', price: '50000.00' };
+
+describe('BinanceService REST retries', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.restoreAllMocks();
+  });
+
+  it('backs off on 429 and then succeeds', async () => {
+    const get = vi.spyOn(axios, 'get')
+      .mockRejectedValueOnce(httpError(429))
+      .mockResolvedValueOnce({ data: ticker });
+    const service = new BinanceService();
+
+    const result = service.getTicker('BTCUSDT');
+    await vi.advanceTimersByTimeAsync(500);
+
+    await expect(result).resolves.toEqual(ticker);
+    expect(get).toHaveBeenCalledTimes(2);
+  });
+
+  it('backs off exponentially on 5xx and then succeeds', async () => {
+    const get = vi.spyOn(axios, 'get')
+      .mockRejectedValueOnce(httpError(500))
+      .mockRejectedValueOnce(httpError(503))
+      .mockResolvedValueOnce({ data: ticker });
+    const service = new BinanceService();
+
+    const result = service.getTicker('BTCUSDT');
+    await vi.advanceTimersByTimeAsync(500);
+    expect(get).toHaveBeenCalledTimes(2);
+    await vi.advanceTimersByTimeAsync(999);
+    expect(get).toHaveBeenCalledTimes(2);
+    await vi.advanceTimersByTimeAsync(1);
+
+    await expect(result).resolves.toEqual(ticker);
+    expect(get).toHaveBeenCalledTimes(3);
+  });
+
+  it('fails fast on a non-retryable 4xx', async () => {
+    const get = vi.spyOn(axios, 'get').mockRejectedValue(httpError(400));
+    const service = new BinanceService();
+
+    await expect(service.getTicker('BTCUSDT')).resolves.toBeNull();
+    expect(get).toHaveBeenCalledTimes(1);
+  });
+
+  it('does not retry a 418 and pauses every other request', async () => {
+    const get = vi.spyOn(axios, 'get').mockRejectedValue(httpError(418, { 'retry-after': '60' }));
+    const service = new BinanceService();
+
+    await expect(service.getTicker('BTCUSDT')).resolves.toBeNull();
+    await expect(service.getOrderBook('BTCUSDT')).resolves.toBeNull();
+    expect(get).toHaveBeenCalledTimes(1);
+
+    get.mockResolvedValue({ data: ticker });
+    await vi.advanceTimersByTimeAsync(60000);
+    await expect(service.getTicker('BTCUSDT')).resolves.toEqual(ticker);
+  });
+
+  it('honors Retry-After and pauses concurrent callers meanwhile', async () => {
+    const get = vi.spyOn(axios, 'get')
+      .mockRejectedValueOnce(httpError(429, { 'retry-after': '2' }))
+      .mockResolvedValue({ data: ticker });
+    const service = new BinanceService();
+
+    const result = service.getTicker('BTCUSDT');
+    await vi.advanceTimersByTimeAsync(0);
+    await expect(service.getOrderBook('BTCUSDT')).resolves.toBeNull();
+    await vi.advanceTimersByTimeAsync(1999);
+    expect(get).toHaveBeenCalledTimes(1);
+    await vi.advanceTimersByTimeAsync(1);
+
+    await expect(result).resolves.toEqual(ticker);
+    expect(get).toHaveBeenCalledTimes(2);
+  });
+
+  it('gives up instead of waiting out a long Retry-After', async () => {
+    const get = vi.spyOn(axios, 'get').mockRejectedValue(httpError(429, { 'retry-after': '120' }));
+    const service = new BinanceService();
+
+    await expect(service.getTicker('BTCUSDT')).resolves.toBeNull();
+    expect(get).toHaveBeenCalledTimes(1);
+  });
+
+  it('stops after maxRetries', async () => {
+    const get = vi.spyOn(axios, 'get').mockRejectedValue(httpError(502));
+    const service = new BinanceService();
+    service.maxRetries = 2;
+
+    const result = service.getTicker('BTCUSDT');
+    await vi.advanceTimersByTimeAsync(10000);
+
+    await expect(result).resolves.toBeNull();
+    expect(get).toHaveBeenCalledTimes(3);
+  });
+});
+
+describe('BinanceService symbol filters', () => {
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it('fetches exchangeInfo once per symbol and caches the filters', async () => {
+    const get = vi.spyOn(axios, 'get').mockResolvedValue({
+      data: {
+        symbols: [
+          {
+            symbol: 'BTCUSDT',
+            filters: [
+              { filterType: 'PRICE_FILTER', tickSize: '0.01000000' },
+              { filterType: 'LOT_SIZE', minQty: '0.00001000', stepSize: '0.00001000' },
+              { filterType: 'NOTIONAL', minNotional: '5.00000000' },
+            ],
+          },
+        ],
+      },
+    });
+    const service = new BinanceService();
+
+    const filters = await service.getSymbolFilters('BTCUSDT');
+    await service.getSymbolFilters('BTCUSDT');
+
+    expect(filters).toEqual({
+      symbol: 'BTCUSDT',
+      stepSize: 0.00001,
+      tickSize: 0.01,
+      minQty: 0.00001,
+      minNotional: 5,
+    });
+    expect(get).toHaveBeenCalledTimes(1);
+    expect(get).toHaveBeenCalledWith('https://api.binance.com/api/v3/exchangeInfo', {
+      params: { symbol: 'BTCUSDT' },
+    });
+  });
+
+  it('returns null for an unknown symbol without caching it', async () => {
+    const get = vi.spyOn(axios, 'get').mockResolvedValue({ data: { symbols: [] } });
+    const service = new BinanceService();
+
+    await expect(service.getSymbolFilters('NOPEUSDT')).resolves.toBeNull();
+    await expect(service.getSymbolFilters('NOPEUSDT')).resolves.toBeNull();
+    expect(get).toHaveBeenCalledTimes(2);
+  });
+});
+
+describe('Binance system status', () => {
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it('parses normal and maintenance responses', () => {
+    expect(parseSystemStatus({ status: 0, msg: 'normal' })).toEqual({
+      maintenance: false,
+      message: 'normal',
+    });
+    expect(parseSystemStatus({ status: 1, msg: 'system_maintenance' })).toEqual({
+      maintenance: true,
+      message: 'system_maintenance',
+    });
+  });
+
+  it('treats a malformed response as unknown', () => {
+    expect(parseSystemStatus({})).toBeNull();
+    expect(parseSystemStatus({ status: 7 })).toBeNull();
+    expect(parseSystemStatus(null)).toBeNull();
+  });
+
+  it('pauses on maintenance and resumes on normal', () => {
+    const maintenance = { maintenance: true, message: 'system_maintenance' };
+    const normal = { maintenance: false, message: 'normal' };
+
+    expect(maintenanceNoGoReason(maintenance, null)).toBe('Exchange maintenance');
+    expect(maintenanceNoGoReason(normal, 'Exchange maintenance')).toBeNull();
+  });
+
+  it('keeps the current state when the status is unknown', () => {
+    expect(maintenanceNoGoReason(null, 'Exchange maintenance')).toBe('Exchange maintenance');
+    expect(maintenanceNoGoReason(null, null)).toBeNull();
+  });
+
+  it('fetches the status from the sapi endpoint', async () => {
+    const get = vi.spyOn(axios, 'get').mockResolvedValue({ data: { status: 1, msg: 'system_maintenance' } });
+    const service = new BinanceService();
+
+    await expect(service.getSystemStatus()).resolves.toEqual({
+      maintenance: true,
+      message: 'system_maintenance',
+    });
+    expect(get).toHaveBeenCalledWith('https://api.binance.com/sapi/v1/system/status', { params: undefined });
+  });
+});
+
+describe('BinanceService base URLs', () => {
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it('stores a testnet override and routes REST calls through it', async () => {
+    const get = vi.spyOn(axios, 'get').mockResolvedValue({ data: [] });
+    const service = new BinanceService('https://testnet.binance.vision/');
+
+    expect(service.baseUrl).toBe('https://testnet.binance.vision');
+    expect(service.wsUrl).toBe('wss://stream.testnet.binance.vision/ws');
+
+    await service.getKlines('BTCUSDT', '1m', 10);
+    expect(get).toHaveBeenCalledWith('https://testnet.binance.vision/api/v3/klines', {
+      params: { symbol: 'BTCUSDT', interval: '1m', limit: 10 },
+    });
+  });
+
+  it('derives the stream URL from the REST base URL', () => {
+    expect(defaultWsUrl('https://api.binance.com')).toBe('wss://stream.binance.com:9443/ws');
+    expect(defaultWsUrl('https://testnet.binance.vision')).toBe('wss://stream.testnet.binance.vision/ws');
+  });
+
+  it('warns when REST and stream point at different environments', () => {
+    const warn = vi.spyOn(console, 'warn').mockImplementation(() => {});
+
+    new BinanceService('https://testnet.binance.vision', 'wss://stream.binance.com:9443/ws');
+    expect(warn).toHaveBeenCalledTimes(1);
+
+    new BinanceService('https://api.binance.com', 'wss://stream.binance.com:9443/ws');
+    expect(warn).toHaveBeenCalledTimes(1);
+  });
+});
@@ -1,18 +1,111 @@
-import axios from 'axios';
-import { Ticker, OrderBook, Kline } from '../types';
+import axios, { AxiosResponse } from 'axios';
+import { Ticker, OrderBook, Kline, SymbolFilters } from '../types';
+import { parseSymbolFilters } from './filters';
 
-const BINANCE_API = 'https://api.binance.com/api/v3';
-const BINANCE_WS = 'wss://stream.binance.com:9443/ws';
+// Set VITE_BINANCE_BASE_URL to https://testnet.binance.vision for Spot testnet dry runs;
+// the stream URL follows it unless VITE_BINANCE_WS_URL overrides it
+const BINANCE_BASE_URL = import.meta.env.VITE_BINANCE_BASE_URL || 'https://api.binance.com';
+const PRODUCTION_WS_URL = 'wss://stream.binance.com:9443/ws';
+const TESTNET_WS_URL = 'wss://stream.testnet.binance.vision/ws';
+const RECONNECT_DELAY_MS = 5000;
+const RETRY_BASE_DELAY_MS = 500;
+const MAX_RETRY_DELAY_MS = 30000;
+const IP_BAN_DEFAULT_MS = 120000;
+
+// 0 keeps reconnecting forever; negative or malformed values fall back to 0
+export function parseMaxReconnects(raw: string | undefined): number {
+  const value = parseInt(raw ?? '0', 10);
+  return Number.isFinite(value) ? Math.max(0, value) : 0;
+}
+
+export function shouldReconnect(failedAttempts: number, maxReconnects: number): boolean {
+  return maxReconnects === 0 || failedAttempts < maxReconnects;
+}
+
+const MAX_RECONNECTS = parseMaxReconnects(import.meta.env.VITE_BINANCE_MAX_RECONNECTS);
+
+const isTestnetUrl = (url: string) => {
+  try {
+    return new URL(url).hostname.includes('testnet');
+  } catch {
+    return false;
+  }
+};
+
+export function defaultWsUrl(baseUrl: string): string {
+  return isTestnetUrl(baseUrl) ? TESTNET_WS_URL : PRODUCTION_WS_URL;
+}
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+export interface SystemStatus {
+  maintenance: boolean;
+  message: string;
+}
+
+export const MAINTENANCE_REASON = 'Exchange maintenance';
+
+// /sapi/v1/system/status answers {"status": 0, "msg": "normal"} or
+// {"status": 1, "msg": "system_maintenance"}; anything else is unknown
+export function parseSystemStatus(data: any): SystemStatus | null {
+  if (data?.status !== 0 && data?.status !== 1) {
+    return null;
+  }
+  return { maintenance: data.status === 1, message: String(data.msg ?? '') };
+}
+
+// Pauses trading on maintenance and resumes on normal. An unknown status
+// (failed or malformed check) keeps the current state rather than flapping.
+export function maintenanceNoGoReason(
+  status: SystemStatus | null,
+  current: string | null
+): string | null {
+  if (!status) {
+    return current;
+  }
+  return status.maintenance ? MAINTENANCE_REASON : null;
+}
+
+export interface StreamStatus {
+  connected: boolean;
+  dead: boolean;
+  reconnectAttempts: number;
+  noGoReason: string | null;
+}
 
 export class BinanceService {
   private ws: WebSocket | null = null;
   private subscribers: Map<string, Set<(data: any) => void>> = new Map();
+  private reconnectAttempts = 0;
+  private reconnectTimer: ReturnType<typeof setTimeout> | null = null;
+  private noGoReason: string | null = null;
+  private statusListeners: Set<(status: StreamStatus) => void> = new Set();
+  private symbolFilters: Map<string, SymbolFilters> = new Map();
+  private readonly maxReconnects: number;
+  maxRetries = 3;
+  private blockedUntil = 0;
+  readonly baseUrl: string;
+  private readonly apiUrl: string;
+  readonly wsUrl: string;
+
+  constructor(
+    baseUrl: string = BINANCE_BASE_URL,
+    wsUrl: string = import.meta.env.VITE_BINANCE_WS_URL || defaultWsUrl(baseUrl),
+    maxReconnects: number = MAX_RECONNECTS
+  ) {
+    this.baseUrl = baseUrl.replace(/\/+$/, '');
+    this.apiUrl = `${this.baseUrl}/api/v3`;
+    this.wsUrl = wsUrl.replace(/\/+$/, '');
+    this.maxReconnects = Math.max(0, maxReconnects);
+
+    if (isTestnetUrl(this.baseUrl) !== isTestnetUrl(this.wsUrl)) {
+      console.warn(`Binance REST (${this.baseUrl}) and stream (${this.wsUrl}) point at different environments`);
+    }
+  }
 
   async getTicker(symbol: string): Promise<Ticker | null> {
     try {
-      const response = await axios.get(`${BINANCE_API}/ticker/24hr`, {
-        params: { symbol }
-      });
+      const response = await this.getWithRetry(`${this.apiUrl}/ticker/24hr`, { symbol });
       return response.data;
     } catch (error) {
       console.error('Error fetching ticker:', error);
@@ -22,7 +115,7 @@ export class BinanceService {
 
   async getAllTickers(): Promise<Ticker[]> {
     try {
-      const response = await axios.get(`${BINANCE_API}/ticker/24hr`);
+      const response = await this.getWithRetry(`${this.apiUrl}/ticker/24hr`);
       return response.data;
     } catch (error) {
       console.error('Error fetching tickers:', error);
@@ -32,9 +125,7 @@ export class BinanceService {
 
   async getOrderBook(symbol: string, limit: number = 20): Promise<OrderBook | null> {
     try {
-      const response = await axios.get(`${BINANCE_API}/depth`, {
-        params: { symbol, limit }
-      });
+      const response = await this.getWithRetry(`${this.apiUrl}/depth`, { symbol, limit });
       return response.data;
     } catch (error) {
       console.error('Error fetching order book:', error);
@@ -48,9 +139,7 @@ export class BinanceService {
     limit: number = 100
   ): Promise<Kline[]> {
     try {
-      const response = await axios.get(`${BINANCE_API}/klines`, {
-        params: { symbol, interval, limit }
-      });
+      const response = await this.getWithRetry(`${this.apiUrl}/klines`, { symbol, interval, limit });
 
       return response.data.map((k: any[]) => ({
         time: k[0] / 1000,
@@ -66,6 +155,38 @@ export class BinanceService {
     }
   }
 
+  async getSystemStatus(): Promise<SystemStatus | null> {
+    try {
+      const response = await this.getWithRetry(`${this.baseUrl}/sapi/v1/system/status`);
+      return parseSystemStatus(response.data);
+    } catch (error) {
+      console.error('Error fetching system status:', error);
+      return null;
+    }
+  }
+
+  // Trading rules rarely change, so they are fetched once per symbol
+  async getSymbolFilters(symbol: string): Promise<SymbolFilters | null> {
+    const cached = this.symbolFilters.get(symbol);
+    if (cached) {
+      return cached;
+    }
+
+    try {
+      const response = await this.getWithRetry(`${this.apiUrl}/exchangeInfo`, { symbol });
+      const symbolInfo = response.data.symbols?.find((s: any) => s.symbol === symbol);
+      if (!symbolInfo) {
+        return null;
+      }
+      const filters = parseSymbolFilters(symbolInfo);
+      this.symbolFilters.set(symbol, filters);
+      return filters;
+    } catch (error) {
+      console.error('Error fetching symbol filters:', error);
+      return null;
+    }
+  }
+
   subscribeToTicker(symbol: string, callback: (ticker: Ticker) => void) {
     const stream = `${symbol.toLowerCase()}@ticker`;
 
@@ -130,18 +251,117 @@ export class BinanceService {
     };
   }
 
-  private connectWebSocket() {
+  private blockUntil(until: number) {
+    this.blockedUntil = Math.max(this.blockedUntil, until);
+  }
+
+  private async getWithRetry(url: string, params?: Record<string, unknown>): Promise<AxiosResponse> {
+    for (let attempt = 0; ; attempt++) {
+      const blockedFor = this.blockedUntil - Date.now();
+      if (blockedFor > 0) {
+        // Fresh requests fail fast so polling callers don't pile up; retries wait out short blocks
+        if (attempt === 0 || blockedFor > MAX_RETRY_DELAY_MS) {
+          throw new Error(`Binance requests paused until ${new Date(this.blockedUntil).toISOString()}`);
+        }
+        await sleep(blockedFor);
+      }
+
+      try {
+        return await axios.get(url, { params });
+      } catch (error) {
+        if (!axios.isAxiosError(error) || !error.response) {
+          throw error;
+        }
+
+        const status = error.response.status;
+        const retryAfter = parseInt(String(error.response.headers['retry-after']), 10);
+        const retryAfterMs = Number.isFinite(retryAfter) ? retryAfter * 1000 : null;
+
+        if (status === 418) {
+          this.blockUntil(Date.now() + (retryAfterMs ?? IP_BAN_DEFAULT_MS));
+          console.error('Binance IP ban (418), pausing all requests');
+          throw error;
+        }
+
+        const retryable = status === 429 || status >= 500;
+        if (!retryable) {
+          throw error;
+        }
+
+        const delay = retryAfterMs ?? RETRY_BASE_DELAY_MS * 2 ** attempt;
+        if (status === 429) {
+          this.blockUntil(Date.now() + delay);
+        }
+
+        if (attempt >= this.maxRetries || delay > MAX_RETRY_DELAY_MS) {
+          throw error;
+        }
+
+        console.warn(`Binance responded ${status}, retrying in ${delay}ms (attempt ${attempt + 1}/${this.maxRetries})`);
+        await sleep(delay);
+      }
+    }
+  }
+
+  getStreamStatus(): StreamStatus {
+    return {
+      connected: this.ws?.readyState === WebSocket.OPEN,
+      dead: this.noGoReason !== null,
+      reconnectAttempts: this.reconnectAttempts,
+      noGoReason: this.noGoReason,
+    };
+  }
+
+  subscribeToStatus(callback: (status: StreamStatus) => void) {
+    this.statusListeners.add(callback);
+    callback(this.getStreamStatus());
+
+    return () => {
+      this.statusListeners.delete(callback);
+    };
+  }
+
+  resetStream() {
+    this.reconnectAttempts = 0;
+    this.noGoReason = null;
+    this.connectWebSocket();
+    this.notifyStatus();
+  }
+
+  private notifyStatus() {
+    const status = this.getStreamStatus();
+    this.statusListeners.forEach(callback => callback(status));
+  }
+
+  private closeSocket() {
+    if (this.reconnectTimer !== null) {
+      clearTimeout(this.reconnectTimer);
+      this.reconnectTimer = null;
+    }
+
     if (this.ws) {
+      this.ws.onclose = null;
       this.ws.close();
+      this.ws = null;
     }
+  }
+
+  private connectWebSocket() {
+    if (this.noGoReason) {
+      return;
+    }
+
+    this.closeSocket();
 
-    this.ws = new WebSocket(`${BINANCE_WS}/!ticker@arr`);
+    this.ws = new WebSocket(`${this.wsUrl}/!ticker@arr`);
 
     this.ws.onopen = () => {
       console.log('WebSocket connected');
+      this.reconnectAttempts = 0;
       this.subscribers.forEach((_, stream) => {
         this.subscribeToStream(stream);
       });
+      this.notifyStatus();
     };
 
     this.ws.onmessage = (event) => {
@@ -172,8 +392,20 @@ export class BinanceService {
     };
 
     this.ws.onclose = () => {
-      console.log('WebSocket closed, reconnecting...');
-      setTimeout(() => this.connectWebSocket(), 5000);
+      if (!shouldReconnect(this.reconnectAttempts, this.maxReconnects)) {
+        this.noGoReason = `Stream dead after ${this.reconnectAttempts} failed reconnects`;
+        console.error(`WebSocket closed: ${this.noGoReason}`);
+        this.notifyStatus();
+        return;
+      }
+
+      this.reconnectAttempts++;
+      console.log(`WebSocket closed, reconnecting (attempt ${this.reconnectAttempts})...`);
+      this.reconnectTimer = setTimeout(() => {
+        this.reconnectTimer = null;
+        this.connectWebSocket();
+      }, RECONNECT_DELAY_MS);
+      this.notifyStatus();
     };
   }
 
@@ -198,11 +430,9 @@ export class BinanceService {
   }
 
   disconnect() {
-    if (this.ws) {
-      this.ws.close();
-      this.ws = null;
-    }
+    this.closeSocket();
     this.subscribers.clear();
+    this.notifyStatus();
   }
 }
 
@@ -0,0 +1,120 @@
+import { describe, it, expect } from 'vitest';
+import {
+  checkRoundingLoss,
+  parseMaxQtyRoundingLossPct,
+  parseSymbolFilters,
+  roundPrice,
+  roundPriceUp,
+  roundQuantity,
+  validateOrder,
+} from './filters';
+import { SymbolFilters } from '../types';
+
+const btc: SymbolFilters = {
+  symbol: 'BTCUSDT',
+  stepSize: 0.00001,
+  tickSize: 0.01,
+  minQty: 0.00001,
+  minNotional: 5,
+};
+
+describe('parseSymbolFilters', () => {
+  it('reads LOT_SIZE, PRICE_FILTER and NOTIONAL from exchangeInfo', () => {
+    const filters = parseSymbolFilters({
+      symbol: 'BTCUSDT',
+      filters: [
+        { filterType: 'PRICE_FILTER', minPrice: '0.01000000', tickSize: '0.01000000' },
+        { filterType: 'LOT_SIZE', minQty: '0.00001000', stepSize: '0.00001000' },
+        { filterType: 'NOTIONAL', minNotional: '5.00000000' },
+      ],
+    });
+
+    expect(filters).toEqual(btc);
+  });
+
+  it('falls back to the legacy MIN_NOTIONAL filter', () => {
+    const filters = parseSymbolFilters({
+      symbol: 'ETHBTC',
+      filters: [{ filterType: 'MIN_NOTIONAL', minNotional: '0.00010000' }],
+    });
+
+    expect(filters.minNotional).toBe(0.0001);
+    expect(filters.stepSize).toBe(0);
+  });
+});
+
+describe('rounding', () => {
+  it('floors quantity to the step size', () => {
+    expect(roundQuantity(btc, 0.123456)).toBe(0.12345);
+  });
+
+  it('keeps a quantity already on the step', () => {
+    expect(roundQuantity(btc, 0.3)).toBe(0.3);
+    expect(roundQuantity({ ...btc, stepSize: 1 }, 7.999)).toBe(7);
+  });
+
+  it('floors price to the tick size', () => {
+    expect(roundPrice(btc, 50123.4567)).toBe(50123.45);
+  });
+
+  it('rounds price up to the tick size on request', () => {
+    expect(roundPriceUp(btc, 50123.4512)).toBe(50123.46);
+    expect(roundPriceUp(btc, 101)).toBe(101);
+  });
+
+  it('leaves values alone when the filter is disabled', () => {
+    expect(roundQuantity({ ...btc, stepSize: 0 }, 0.123456)).toBe(0.123456);
+  });
+});
+
+describe('validateOrder', () => {
+  it('accepts an order within the filters', () => {
+    expect(validateOrder(btc, 0.001, 50000)).toBeNull();
+  });
+
+  it('rejects an order below the minimum notional', () => {
+    expect(validateOrder(btc, 0.00005, 50000)).toBe(
+      'Order value 2.50 is below the minimum notional of 5 for BTCUSDT'
+    );
+  });
+
+  it('rejects a quantity that rounded to zero', () => {
+    expect(validateOrder(btc, 0, 50000)).toMatch(/below the minimum of 0.00001/);
+  });
+});
+
+describe('checkRoundingLoss', () => {
+  it('rejects rounding that loses more than the limit', () => {
+    // 1.9 on a step of 1 rounds to 1, losing ~47% of the size
+    const rounded = roundQuantity({ ...btc, stepSize: 1 }, 1.9);
+
+    expect(checkRoundingLoss(1.9, rounded, 10)).toBe('Quantity rounding loss too large');
+  });
+
+  it('rejects a quantity that rounded to zero', () => {
+    expect(checkRoundingLoss(0.000009, 0, 10)).toBe('Quantity rounding loss too large');
+  });
+
+  it('accepts a loss within the limit', () => {
+    expect(checkRoundingLoss(0.123456, roundQuantity(btc, 0.123456), 10)).toBeNull();
+    expect(checkRoundingLoss(1.05, 1, 10)).toBeNull();
+  });
+
+  it('rejects any loss at a limit of 0', () => {
+    expect(checkRoundingLoss(1.05, 1, 0)).toBe('Quantity rounding loss too large');
+    expect(checkRoundingLoss(1, 1, 0)).toBeNull();
+  });
+});
+
+describe('parseMaxQtyRoundingLossPct', () => {
+  it('defaults to 10 when unset or invalid', () => {
+    expect(parseMaxQtyRoundingLossPct(undefined)).toBe(10);
+    expect(parseMaxQtyRoundingLossPct('abc')).toBe(10);
+  });
+
+  it('keeps 0 and clamps negatives to it', () => {
+    expect(parseMaxQtyRoundingLossPct('0')).toBe(0);
+    expect(parseMaxQtyRoundingLossPct('-5')).toBe(0);
+    expect(parseMaxQtyRoundingLossPct('2.5')).toBe(2.5);
+  });
+});
@@ -0,0 +1,87 @@
+import { SymbolFilters } from '../types';
+
+// 0 is valid and rejects any rounding loss
+export function parseMaxQtyRoundingLossPct(raw: string | undefined): number {
+  const value = parseFloat(raw ?? '');
+  return Number.isFinite(value) ? Math.max(0, value) : 10;
+}
+
+const MAX_QTY_ROUNDING_LOSS_PCT = parseMaxQtyRoundingLossPct(
+  import.meta.env.VITE_MAX_QTY_ROUNDING_LOSS_PCT
+);
+
+// Parses one symbol entry of /api/v3/exchangeInfo. Spot symbols carry either
+// the legacy MIN_NOTIONAL filter or its NOTIONAL replacement.
+export function parseSymbolFilters(symbolInfo: any): SymbolFilters {
+  const find = (type: string) => symbolInfo.filters?.find((f: any) => f.filterType === type);
+  const lotSize = find('LOT_SIZE');
+  const priceFilter = find('PRICE_FILTER');
+  const notional = find('NOTIONAL') ?? find('MIN_NOTIONAL');
+
+  return {
+    symbol: symbolInfo.symbol,
+    stepSize: parseFloat(lotSize?.stepSize ?? '0'),
+    tickSize: parseFloat(priceFilter?.tickSize ?? '0'),
+    minQty: parseFloat(lotSize?.minQty ?? '0'),
+    minNotional: parseFloat(notional?.minNotional ?? '0'),
+  };
+}
+
+function stepDecimals(step: number): number {
+  const [mantissa, exponent] = step.toExponential().split('e');
+  const fraction = mantissa.split('.')[1]?.length ?? 0;
+  return Math.max(0, fraction - parseInt(exponent, 10));
+}
+
+// Floors to a multiple of `step`; a step of 0 means the filter is disabled.
+function floorToStep(value: number, step: number): number {
+  if (step <= 0) return value;
+  const steps = Math.floor(value / step + 1e-9);
+  return parseFloat((steps * step).toFixed(stepDecimals(step)));
+}
+
+function ceilToStep(value: number, step: number): number {
+  if (step <= 0) return value;
+  const steps = Math.ceil(value / step - 1e-9);
+  return parseFloat((steps * step).toFixed(stepDecimals(step)));
+}
+
+export function roundQuantity(filters: SymbolFilters, quantity: number): number {
+  return floorToStep(quantity, filters.stepSize);
+}
+
+export function roundPrice(filters: SymbolFilters, price: number): number {
+  return floorToStep(price, filters.tickSize);
+}
+
+export function roundPriceUp(filters: SymbolFilters, price: number): number {
+  return ceilToStep(price, filters.tickSize);
+}
+
+// Reason Binance would reject the order, or null. Expects rounded values.
+export function validateOrder(
+  filters: SymbolFilters,
+  quantity: number,
+  price: number
+): string | null {
+  if (quantity <= 0 || quantity < filters.minQty) {
+    return `Quantity ${quantity} is below the minimum of ${filters.minQty} for ${filters.symbol}`;
+  }
+  const notional = quantity * price;
+  if (notional < filters.minNotional) {
+    return `Order value ${notional.toFixed(2)} is below the minimum notional of ${filters.minNotional} for ${filters.symbol}`;
+  }
+  return null;
+}
+
+// Flags step rounding that ate too much of the intended size, which happens
+// on small orders in coarse-step symbols.
+export function checkRoundingLoss(
+  intended: number,
+  rounded: number,
+  maxLossPct: number = MAX_QTY_ROUNDING_LOSS_PCT
+): string | null {
+  if (intended <= 0) return null;
+  const lossPct = ((intended - rounded) / intended) * 100;
+  return lossPct > maxLossPct ? 'Quantity rounding loss too large' : null;
+}
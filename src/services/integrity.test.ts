@@ -0,0 +1,76 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { candleIntegrityIssue, IntegrityMonitor, parseMaxDataIntegrityFailures } from './integrity';
+import { kline } from '../test/kline';
+
+describe('parseMaxDataIntegrityFailures', () => {
+  it('defaults to 3 when unset or invalid', () => {
+    expect(parseMaxDataIntegrityFailures(undefined)).toBe(3);
+    expect(parseMaxDataIntegrityFailures('abc')).toBe(3);
+  });
+
+  it('keeps 0 and clamps negatives to it', () => {
+    expect(parseMaxDataIntegrityFailures('0')).toBe(0);
+    expect(parseMaxDataIntegrityFailures('-2')).toBe(0);
+  });
+});
+
+describe('candleIntegrityIssue', () => {
+  it('passes a sane candle', () => {
+    expect(candleIntegrityIssue(kline({ time: 60 }), kline())).toBeNull();
+  });
+
+  it('rejects non-positive prices', () => {
+    expect(candleIntegrityIssue(kline({ low: -1 }))).toBe('Non-positive price');
+    expect(candleIntegrityIssue(kline({ close: NaN }))).toBe('Non-positive price');
+  });
+
+  it('rejects broken OHLC ordering', () => {
+    expect(candleIntegrityIssue(kline({ high: 99.5 }))).toBe('Invalid OHLC ordering');
+    expect(candleIntegrityIssue(kline({ low: 102, high: 101.5 }))).toBe('Invalid OHLC ordering');
+  });
+
+  it('rejects a timestamp earlier than the previous candle', () => {
+    expect(candleIntegrityIssue(kline(), kline({ time: 60 }))).toBe('Candle time went backwards');
+  });
+
+  it('allows updates to the same open candle', () => {
+    expect(candleIntegrityIssue(kline({ time: 60 }), kline({ time: 60 }))).toBeNull();
+  });
+});
+
+describe('IntegrityMonitor', () => {
+  beforeEach(() => {
+    vi.spyOn(console, 'warn').mockImplementation(() => {});
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it('trips on a backwards-timestamp sequence beyond the threshold', () => {
+    const monitor = new IntegrityMonitor(2);
+
+    expect(monitor.record(kline({ time: 300 }))).toBeNull();
+    expect(monitor.record(kline({ time: 240 }))).toBeNull();
+    expect(monitor.record(kline({ time: 180 }))).toBeNull();
+    expect(monitor.record(kline({ time: 120 }))).toBe('Data integrity failure');
+    expect(monitor.tripped()).toBe(true);
+  });
+
+  it('stays tripped on good data until reset', () => {
+    const monitor = new IntegrityMonitor(0);
+    monitor.record(kline({ low: -1 }));
+
+    expect(monitor.record(kline({ time: 60 }))).toBe('Data integrity failure');
+    monitor.reset();
+    expect(monitor.record(kline({ time: 120 }))).toBeNull();
+  });
+
+  it('does not trip on a healthy stream', () => {
+    const monitor = new IntegrityMonitor(0);
+
+    for (let i = 0; i < 10; i++) {
+      expect(monitor.record(kline({ time: i * 60 }))).toBeNull();
+    }
+  });
+});
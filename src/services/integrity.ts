@@ -0,0 +1,67 @@
+import { Kline } from '../types';
+
+// 0 is valid and trips the monitor on the first bad candle
+export function parseMaxDataIntegrityFailures(raw: string | undefined): number {
+  const value = parseInt(raw ?? '', 10);
+  return Number.isFinite(value) ? Math.max(0, value) : 3;
+}
+
+const MAX_DATA_INTEGRITY_FAILURES = parseMaxDataIntegrityFailures(
+  import.meta.env.VITE_MAX_DATA_INTEGRITY_FAILURES
+);
+
+export const DATA_INTEGRITY_REASON = 'Data integrity failure';
+
+export function candleIntegrityIssue(candle: Kline, previous?: Kline | null): string | null {
+  const prices = [candle.open, candle.high, candle.low, candle.close];
+  if (prices.some((price) => !Number.isFinite(price) || price <= 0)) {
+    return 'Non-positive price';
+  }
+  if (!Number.isFinite(candle.volume) || candle.volume < 0) {
+    return 'Negative volume';
+  }
+  if (
+    candle.low > candle.high ||
+    candle.high < Math.max(candle.open, candle.close) ||
+    candle.low > Math.min(candle.open, candle.close)
+  ) {
+    return 'Invalid OHLC ordering';
+  }
+  if (previous && candle.time < previous.time) {
+    return 'Candle time went backwards';
+  }
+  return null;
+}
+
+// Counts integrity failures on one kline stream. Once more than
+// `maxFailures` have been seen it stays tripped until reset.
+export class IntegrityMonitor {
+  readonly maxFailures: number;
+  failures = 0;
+  private last: Kline | null = null;
+
+  constructor(maxFailures: number = MAX_DATA_INTEGRITY_FAILURES) {
+    this.maxFailures = maxFailures;
+  }
+
+  // Returns the no-go reason when this candle trips the monitor
+  record(candle: Kline): string | null {
+    const issue = candleIntegrityIssue(candle, this.last);
+    if (issue) {
+      this.failures++;
+      console.warn(`Kline integrity check failed at ${candle.time}: ${issue} (${this.failures}/${this.maxFailures})`);
+    } else {
+      this.last = candle;
+    }
+    return this.tripped() ? DATA_INTEGRITY_REASON : null;
+  }
+
+  tripped(): boolean {
+    return this.failures > this.maxFailures;
+  }
+
+  reset() {
+    this.failures = 0;
+    this.last = null;
+  }
+}
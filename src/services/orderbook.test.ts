@@ -0,0 +1,153 @@
+import { describe, it, expect } from 'vitest';
+import { simulateMarketFill, bookPriceDecimals, isOrderBookStale, makerEntryPrice, bestPrice } from './orderbook';
+import { OrderBook, SymbolFilters } from '../types';
+
+const book: OrderBook = {
+  bids: [
+    ['99.00000000', '1.00000000'],
+    ['98.00000000', '2.00000000'],
+  ],
+  asks: [
+    ['101.00000000', '1.00000000'],
+    ['102.00000000', '2.00000000'],
+    ['103.50000000', '1.00000000'],
+  ],
+};
+
+describe('simulateMarketFill', () => {
+  it('fills within the first level at its price', () => {
+    const fill = simulateMarketFill(book, 'BUY', 0.5);
+
+    expect(fill.filledQuantity).toBeCloseTo(0.5);
+    expect(fill.averagePrice).toBeCloseTo(101);
+    expect(fill.levelsConsumed).toBe(1);
+    expect(fill.partial).toBe(false);
+  });
+
+  it('computes a volume-weighted price across levels', () => {
+    const fill = simulateMarketFill(book, 'BUY', 2.5);
+
+    // 1 @ 101 + 1.5 @ 102
+    expect(fill.notional).toBeCloseTo(254);
+    expect(fill.averagePrice).toBeCloseTo(254 / 2.5);
+    expect(fill.levelsConsumed).toBe(2);
+    expect(fill.partial).toBe(false);
+  });
+
+  it('walks bids for sells', () => {
+    const fill = simulateMarketFill(book, 'SELL', 2);
+
+    expect(fill.averagePrice).toBeCloseTo((99 + 98) / 2);
+  });
+
+  it('reports a partial fill when the fetched depth runs out', () => {
+    const fill = simulateMarketFill(book, 'BUY', 5);
+
+    expect(fill.filledQuantity).toBeCloseTo(4);
+    expect(fill.levelsConsumed).toBe(3);
+    expect(fill.levelsAvailable).toBe(3);
+    expect(fill.partial).toBe(true);
+  });
+
+  it('does not treat float residue as a partial fill', () => {
+    const fill = simulateMarketFill(
+      { bids: [], asks: [['1', '0.1'], ['1', '0.2']] },
+      'BUY',
+      0.3
+    );
+
+    expect(fill.partial).toBe(false);
+  });
+
+  it('fills nothing for a zero quantity', () => {
+    const fill = simulateMarketFill(book, 'BUY', 0);
+
+    expect(fill.filledQuantity).toBe(0);
+    expect(fill.averagePrice).toBe(0);
+    expect(fill.levelsConsumed).toBe(0);
+    expect(fill.partial).toBe(false);
+  });
+});
+
+describe('bookPriceDecimals', () => {
+  it('uses the finest price precision quoted in the book', () => {
+    expect(bookPriceDecimals(book)).toBe(1);
+    expect(bookPriceDecimals({ bids: [['0.00001234', '10']], asks: [] })).toBe(8);
+  });
+});
+
+describe('isOrderBookStale', () => {
+  it('treats a book that never updated as stale', () => {
+    expect(isOrderBookStale(null, 10, 1000)).toBe(true);
+  });
+
+  it('flags a book older than the max age', () => {
+    expect(isOrderBookStale(0, 10, 10000)).toBe(false);
+    expect(isOrderBookStale(0, 10, 10001)).toBe(true);
+  });
+});
+
+describe('makerEntryPrice', () => {
+  const filters = (tickSize: number): SymbolFilters => ({
+    symbol: 'TESTUSDT',
+    stepSize: 0.001,
+    tickSize,
+    minQty: 0,
+    minNotional: 0,
+  });
+  const cents = filters(0.01);
+
+  it('posts at the best bid or ask when passive', () => {
+    expect(makerEntryPrice(book, 'BUY', 0, cents)).toBe(99);
+    expect(makerEntryPrice(book, 'SELL', 0, cents)).toBe(101);
+  });
+
+  it('posts at the mid when fully aggressive', () => {
+    expect(makerEntryPrice(book, 'BUY', 1, cents)).toBe(100);
+    expect(makerEntryPrice(book, 'SELL', 1, cents)).toBe(100);
+  });
+
+  it('interpolates and clamps aggressiveness', () => {
+    expect(makerEntryPrice(book, 'BUY', 0.5, cents)).toBe(99.5);
+    expect(makerEntryPrice(book, 'BUY', 2, cents)).toBe(100);
+    expect(makerEntryPrice(book, 'SELL', -1, cents)).toBe(101);
+  });
+
+  it('rounds away from the mid on a one-tick spread', () => {
+    const tight: OrderBook = { bids: [['99.0', '1']], asks: [['99.1', '1']] };
+
+    expect(makerEntryPrice(tight, 'BUY', 1, filters(0.1))).toBe(99);
+    expect(makerEntryPrice(tight, 'SELL', 1, filters(0.1))).toBe(99.1);
+  });
+
+  it('rounds to the tick, not the precision of the book prices', () => {
+    const coarse: OrderBook = { bids: [['0.52300000', '1']], asks: [['0.52400000', '1']] };
+
+    expect(makerEntryPrice(coarse, 'BUY', 1, filters(0.0001))).toBe(0.5235);
+    expect(makerEntryPrice(coarse, 'SELL', 1, filters(0.0001))).toBe(0.5235);
+  });
+
+  it('returns null without both sides of the book', () => {
+    expect(makerEntryPrice({ bids: [], asks: book.asks }, 'BUY', 0, cents)).toBeNull();
+  });
+});
+
+describe('bestPrice', () => {
+  it('prefers the last trade price', () => {
+    expect(bestPrice(100.5, book, 98)).toBe(100.5);
+  });
+
+  it('falls back to the book mid without a trade price', () => {
+    expect(bestPrice(0, book, 98)).toBe(100);
+  });
+
+  it('falls back to the last close without a two-sided book', () => {
+    expect(bestPrice(0, { bids: book.bids, asks: [] }, 98)).toBe(98);
+    expect(bestPrice(0, null, 98)).toBe(98);
+  });
+
+  it('returns null when no source has data', () => {
+    expect(bestPrice(0, null, null)).toBeNull();
+    expect(bestPrice(0, { bids: [], asks: [] }, 0)).toBeNull();
+  });
+});
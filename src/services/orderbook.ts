@@ -0,0 +1,94 @@
+import { OrderBook, Fill, SymbolFilters } from '../types';
+import { roundPrice, roundPriceUp } from './filters';
+
+export function simulateMarketFill(
+  orderBook: OrderBook,
+  side: 'BUY' | 'SELL',
+  quantity: number
+): Fill {
+  const levels = side === 'BUY' ? orderBook.asks : orderBook.bids;
+  const epsilon = quantity * 1e-9;
+
+  let remaining = quantity;
+  let filledQuantity = 0;
+  let notional = 0;
+  let levelsConsumed = 0;
+
+  for (const [levelPrice, levelAmount] of levels) {
+    if (remaining <= epsilon) break;
+
+    const price = parseFloat(levelPrice);
+    const amount = parseFloat(levelAmount);
+    const take = Math.min(remaining, amount);
+
+    filledQuantity += take;
+    notional += take * price;
+    remaining -= take;
+    levelsConsumed++;
+  }
+
+  return {
+    side,
+    requestedQuantity: quantity,
+    filledQuantity,
+    averagePrice: filledQuantity > 0 ? notional / filledQuantity : 0,
+    notional,
+    levelsConsumed,
+    levelsAvailable: levels.length,
+    partial: remaining > epsilon,
+  };
+}
+
+export function bookPriceDecimals(orderBook: OrderBook): number {
+  let decimals = 0;
+  for (const [price] of [...orderBook.bids, ...orderBook.asks]) {
+    const fraction = price.split('.')[1]?.replace(/0+$/, '') ?? '';
+    decimals = Math.max(decimals, fraction.length);
+  }
+  return decimals;
+}
+
+export function isOrderBookStale(
+  updatedAt: number | null,
+  maxAgeSecs: number,
+  now: number = Date.now()
+): boolean {
+  return updatedAt === null || now - updatedAt > maxAgeSecs * 1000;
+}
+
+// Limit price for a maker entry: 0 posts at the best bid/ask, 1 at the mid.
+// Rounded away from the mid to the symbol's tick so it never crosses.
+export function makerEntryPrice(
+  orderBook: OrderBook,
+  side: 'BUY' | 'SELL',
+  aggressiveness: number,
+  filters: SymbolFilters
+): number | null {
+  if (orderBook.bids.length === 0 || orderBook.asks.length === 0) return null;
+
+  const bestBid = parseFloat(orderBook.bids[0][0]);
+  const bestAsk = parseFloat(orderBook.asks[0][0]);
+  const mid = (bestBid + bestAsk) / 2;
+  const weight = Math.min(1, Math.max(0, aggressiveness));
+
+  if (side === 'BUY') {
+    return roundPrice(filters, bestBid + weight * (mid - bestBid));
+  }
+  return roundPriceUp(filters, bestAsk - weight * (bestAsk - mid));
+}
+
+// Reference price for a symbol from whichever source is live: the last trade
+// price, then the book mid, then the last candle close. Zero means no data.
+export function bestPrice(
+  lastPrice: number,
+  orderBook: OrderBook | null,
+  lastClose: number | null
+): number | null {
+  if (lastPrice > 0) return lastPrice;
+
+  const bestBid = orderBook?.bids.length ? parseFloat(orderBook.bids[0][0]) : 0;
+  const bestAsk = orderBook?.asks.length ? parseFloat(orderBook.asks[0][0]) : 0;
+  if (bestBid > 0 && bestAsk > 0) return (bestBid + bestAsk) / 2;
+
+  return lastClose !== null && lastClose > 0 ? lastClose : null;
+}
@@ -23,8 +23,26 @@ interface AppStore {
   chartInterval: string;
   setChartInterval: (interval: string) => void;
 
+  chartOscillator: string | null;
+  setChartOscillator: (id: string | null) => void;
+
+  chartOverlays: string[];
+  toggleChartOverlay: (id: string) => void;
+
   sidebarCollapsed: boolean;
   toggleSidebar: () => void;
+
+  simulateDepthFills: boolean;
+  setSimulateDepthFills: (enabled: boolean) => void;
+
+  makerAggressiveness: number;
+  setMakerAggressiveness: (aggressiveness: number) => void;
+
+  exchangeNoGoReason: string | null;
+  setExchangeNoGoReason: (reason: string | null) => void;
+
+  dataNoGoReason: string | null;
+  setDataNoGoReason: (reason: string | null) => void;
 }
 
 export const useStore = create<AppStore>((set) => ({
@@ -55,6 +73,29 @@ export const useStore = create<AppStore>((set) => ({
   chartInterval: '1h',
   setChartInterval: (interval) => set({ chartInterval: interval }),
 
+  chartOscillator: null,
+  setChartOscillator: (id) => set({ chartOscillator: id }),
+
+  chartOverlays: [],
+  toggleChartOverlay: (id) =>
+    set((state) => ({
+      chartOverlays: state.chartOverlays.includes(id)
+        ? state.chartOverlays.filter((overlay) => overlay !== id)
+        : [...state.chartOverlays, id],
+    })),
+
   sidebarCollapsed: false,
   toggleSidebar: () => set((state) => ({ sidebarCollapsed: !state.sidebarCollapsed })),
+
+  simulateDepthFills: false,
+  setSimulateDepthFills: (enabled) => set({ simulateDepthFills: enabled }),
+
+  makerAggressiveness: 0,
+  setMakerAggressiveness: (aggressiveness) => set({ makerAggressiveness: aggressiveness }),
+
+  exchangeNoGoReason: null,
+  setExchangeNoGoReason: (reason) => set({ exchangeNoGoReason: reason }),
+
+  dataNoGoReason: null,
+  setDataNoGoReason: (reason) => set({ dataNoGoReason: reason }),
 }));
@@ -0,0 +1,16 @@
+import { Kline } from '../types';
+
+// Test candle opening at its close with `range` either side, unless the
+// overrides say otherwise.
+export function kline(overrides: Partial<Kline> = {}, range = 1): Kline {
+  const close = overrides.close ?? 100;
+  return {
+    time: 0,
+    open: close,
+    high: close + range,
+    low: close - range,
+    close,
+    volume: 1,
+    ...overrides,
+  };
+}
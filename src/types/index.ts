@@ -75,3 +75,22 @@ export interface MarketStats {
   dayChange: number;
   dayChangePercentage: number;
 }
+
+export interface Fill {
+  side: 'BUY' | 'SELL';
+  requestedQuantity: number;
+  filledQuantity: number;
+  averagePrice: number;
+  notional: number;
+  levelsConsumed: number;
+  levelsAvailable: number;
+  partial: boolean;
+}
+
+export interface SymbolFilters {
+  symbol: string;
+  stepSize: number;
+  tickSize: number;
+  minQty: number;
+  minNotional: number;
+}
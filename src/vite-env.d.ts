@@ -3,6 +3,13 @@
 interface ImportMetaEnv {
   readonly VITE_SUPABASE_URL: string;
   readonly VITE_SUPABASE_ANON_KEY: string;
+  readonly VITE_BINANCE_BASE_URL?: string;
+  readonly VITE_BINANCE_WS_URL?: string;
+  readonly VITE_BINANCE_MAX_RECONNECTS?: string;
+  readonly VITE_MAX_ORDERBOOK_AGE_SECS?: string;
+  readonly VITE_MAX_WICK_PCT?: string;
+  readonly VITE_MAX_QTY_ROUNDING_LOSS_PCT?: string;
+  readonly VITE_MAX_DATA_INTEGRITY_FAILURES?: string;
 }
 
 interface ImportMeta {